@@ -0,0 +1,95 @@
+//! Times a full simulated day on a large garden, failing if a day no longer fits in a frame
+//! Run with: cargo bench --no-default-features --bench daily_simulation
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use eden2::gameplay::lifecycle::{DailySimulation, EcosystemPopulation, Genome, MatterType, DEFAULT_COHORT_THRESHOLD};
+use eden2::gameplay::species::{get_all_species, Species};
+
+const CREATURE_COUNT: usize = 10_000;
+/// Gardens this large are only playable in cohort mode
+const HUGE_CREATURE_COUNT: usize = 100_000;
+const DAYS: usize = 5;
+/// Generations of mutation separating the varied garden's creatures from their founders
+const GENERATIONS: usize = 10;
+/// One frame at 60 FPS
+const FRAME_BUDGET: Duration = Duration::from_micros(16_667);
+
+fn large_garden(creature_count: usize, cohort_threshold: Option<u32>, generations: usize) -> EcosystemPopulation {
+    let mut ecosystem = EcosystemPopulation {
+        cohort_threshold,
+        ..Default::default()
+    };
+
+    // Creatures of a species share one definition, as offspring share their parents'
+    let mut species: Vec<Arc<Species>> = get_all_species().into_values().map(Arc::new).collect();
+    species.sort_by_key(|s| s.name);
+
+    // Each creature drifts from its founders on its own, as offspring mutate in play
+    let mut rng = fastrand::Rng::with_seed(7);
+    for index in 0..creature_count {
+        let creature_id = ecosystem.add_mature_creature(species[index % species.len()].clone(), (3, 2));
+        let mut genome = Genome::default();
+        for _ in 0..generations {
+            genome = genome.mutated(&mut rng, ecosystem.genetics.mutation_rate);
+        }
+        if let Some(creature) = ecosystem.creatures.get_mut(creature_id) {
+            creature.genome = genome;
+        }
+    }
+
+    // Plenty of every matter type on every tile so the day runs all phases rather than starving everyone
+    for tile in ecosystem.grid.tiles_mut() {
+        for matter_type in MatterType::all() {
+            tile.add_matter(matter_type, 1_000_000);
+        }
+    }
+
+    ecosystem
+}
+
+fn time_days(label: &str, mut ecosystem: EcosystemPopulation) {
+    let daily_simulation = DailySimulation::default();
+    let creature_count = ecosystem.total_population();
+    let mut timings = Vec::with_capacity(DAYS);
+
+    for _ in 0..DAYS {
+        let start = Instant::now();
+        daily_simulation
+            .simulate_day(&mut ecosystem)
+            .expect("day should simulate");
+        timings.push(start.elapsed());
+    }
+
+    timings.sort();
+    let median = timings[timings.len() / 2];
+    println!(
+        "{} creatures ({}, {} cohorts): median day {:?} (fastest {:?}, slowest {:?}), frame budget {:?}",
+        creature_count,
+        label,
+        ecosystem.cohorts.len(),
+        median,
+        timings[0],
+        timings[timings.len() - 1],
+        FRAME_BUDGET,
+    );
+
+    // Guards against regressions; the fastest day is checked so a busy machine can't fail the bench,
+    // while a real slowdown shows up on every day
+    assert!(
+        timings[0] <= FRAME_BUDGET,
+        "{} creatures ({}): fastest day {:?} is over the frame budget of {:?}",
+        creature_count,
+        label,
+        timings[0],
+        FRAME_BUDGET,
+    );
+}
+
+fn main() {
+    time_days("individuals", large_garden(CREATURE_COUNT, None, 0));
+    time_days("cohorts", large_garden(CREATURE_COUNT, Some(DEFAULT_COHORT_THRESHOLD), 0));
+    time_days("cohorts", large_garden(HUGE_CREATURE_COUNT, Some(DEFAULT_COHORT_THRESHOLD), 0));
+    time_days("cohorts, varied genomes", large_garden(CREATURE_COUNT, Some(DEFAULT_COHORT_THRESHOLD), GENERATIONS));
+    time_days("cohorts, varied genomes", large_garden(HUGE_CREATURE_COUNT, Some(DEFAULT_COHORT_THRESHOLD), GENERATIONS));
+}
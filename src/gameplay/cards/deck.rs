@@ -1,4 +1,3 @@
-use bevy::prelude::*;
 use rand::seq::SliceRandom;
 use rand::thread_rng;
 use super::card::Card;
@@ -7,7 +6,8 @@ use crate::gameplay::species::animals::get_animal_tier_1;
 use crate::gameplay::species::decomposers::get_fungi_tier_1;
 
 /// Deck resource for managing the collection of cards available to draw from
-#[derive(Resource, Clone)]
+#[derive(Clone)]
+#[cfg_attr(feature = "bevy", derive(bevy::prelude::Resource))]
 pub struct Deck {
     pub cards: Vec<Card>,
 }
@@ -1,10 +1,18 @@
-use bevy::prelude::*;
 use super::{card::Card};
-use crate::gameplay::lifecycle::{AddSpeciesToEcosystemEvent, SimulateDayEvent};
+use crate::gameplay::lifecycle::TilePosition;
+
+#[cfg(feature = "bevy")]
+use bevy::prelude::*;
+#[cfg(feature = "bevy")]
+use crate::gameplay::lifecycle::{AddSpeciesToEcosystemEvent, EcosystemPopulation, SimulateDayEvent};
+#[cfg(feature = "bevy")]
+use crate::gameplay::event_log::{EcosystemLog, LogEventKind};
+#[cfg(feature = "bevy")]
 use crate::gameplay::species::get_species;
 
 /// Hand resource for managing the player's current cards
-#[derive(Resource, Clone)]
+#[derive(Clone)]
+#[cfg_attr(feature = "bevy", derive(bevy::prelude::Resource))]
 pub struct Hand {
     pub cards: Vec<Card>,
 }
@@ -53,18 +61,20 @@ impl Hand {
 
 // ===== EVENTS =====
 
-#[derive(Event)]
+#[cfg_attr(feature = "bevy", derive(bevy::prelude::Event))]
 pub struct PlayCardEvent {
     pub hand_index: usize,
+    pub location: TilePosition, // Garden tile to place the card's species on
 }
 
-#[derive(Event)]
+#[cfg_attr(feature = "bevy", derive(bevy::prelude::Event))]
 pub struct DiscardCardEvent {
     pub hand_index: usize,
 }
 
 // ===== SYSTEMS =====
 
+#[cfg(feature = "bevy")]
 pub fn handle_play_card_event(
     mut game_state: ResMut<crate::gameplay::GameState>,
     mut play_card_events: EventReader<PlayCardEvent>,
@@ -91,33 +101,32 @@ pub fn handle_play_card_event(
         }
 
         // Add species to ecosystem with appropriate starting biomass
-        let starting_biomass = match species_def.biomass_composition {
-            crate::gameplay::species::BiomassComposition::Plant => (3, 0), // Start with 3 plant matter
-            crate::gameplay::species::BiomassComposition::Animal => (0, 2), // Start with 2 animal matter
-            crate::gameplay::species::BiomassComposition::Mixed { plant_ratio, animal_ratio } => {
-                // For mixed compositions, split the total biomass proportionally
-                let total_biomass = 3.0;
-                let plant_amount = (total_biomass * plant_ratio) as u32;
-                let animal_amount = (total_biomass * animal_ratio) as u32;
-                (plant_amount, animal_amount)
-            }
-        };
-
         add_species_events.write(AddSpeciesToEcosystemEvent { 
             species: species_def.clone(),
-            starting_biomass,
+            starting_biomass: species_def.starting_biomass(),
+            location: event.location,
         });
     }
 }
 
+#[cfg(feature = "bevy")]
 pub fn handle_discard_card_event(
     mut game_state: ResMut<crate::gameplay::GameState>,
+    ecosystem: Res<EcosystemPopulation>,
+    mut event_log: ResMut<EcosystemLog>,
     mut discard_events: EventReader<DiscardCardEvent>,
     mut simulate_day_events: EventWriter<SimulateDayEvent>,
 ) {
     for event in discard_events.read() {
         // Remove the card from hand
-        if game_state.hand.remove_card(event.hand_index).is_some() {
+        if let Some(card) = game_state.hand.remove_card(event.hand_index) {
+            event_log.record(
+                ecosystem.current_day,
+                LogEventKind::CardDiscarded,
+                Some(card.name()),
+                format!("Discarded {} card", card.name()),
+            );
+
             // Draw a new card to replace it
             if let Some(new_card) = game_state.deck.draw() {
                 game_state.hand.add_card(new_card);
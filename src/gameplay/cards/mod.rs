@@ -5,4 +5,6 @@ pub mod hand;
 // Re-export the main types
 pub use card::Card;
 pub use deck::Deck;
-pub use hand::{Hand, PlayCardEvent, DiscardCardEvent, handle_play_card_event, handle_discard_card_event};
+pub use hand::{Hand, PlayCardEvent, DiscardCardEvent};
+#[cfg(feature = "bevy")]
+pub use hand::{handle_play_card_event, handle_discard_card_event};
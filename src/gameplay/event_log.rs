@@ -0,0 +1,250 @@
+use std::collections::{BTreeSet, HashMap};
+use crate::gameplay::lifecycle::{CreatureId, DailyResult, DeathCause, LifecycleEvent};
+
+/// Oldest entries are dropped once the log grows past this size
+const MAX_LOG_ENTRIES: usize = 500;
+
+/// Kinds of happenings recorded in the ecosystem log
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LogEventKind {
+    Introduced,
+    Born,
+    Matured,
+    Died,
+    CardDiscarded,
+    Ecological,
+    DayPassed,
+}
+
+impl LogEventKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LogEventKind::Introduced => "Introduced",
+            LogEventKind::Born => "Born",
+            LogEventKind::Matured => "Matured",
+            LogEventKind::Died => "Died",
+            LogEventKind::CardDiscarded => "Discarded",
+            LogEventKind::Ecological => "Ecological",
+            LogEventKind::DayPassed => "Day Passed",
+        }
+    }
+
+    pub fn all() -> Vec<LogEventKind> {
+        vec![
+            LogEventKind::Introduced,
+            LogEventKind::Born,
+            LogEventKind::Matured,
+            LogEventKind::Died,
+            LogEventKind::CardDiscarded,
+            LogEventKind::Ecological,
+            LogEventKind::DayPassed,
+        ]
+    }
+}
+
+/// A single line in the ecosystem log
+#[derive(Clone, Debug)]
+pub struct LogEntry {
+    pub day: u32,
+    pub kind: LogEventKind,
+    pub species: Option<String>,
+    pub message: String,
+}
+
+/// Player-facing history of ecosystem happenings and player actions
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "bevy", derive(bevy::prelude::Resource))]
+pub struct EcosystemLog {
+    pub entries: Vec<LogEntry>,
+}
+
+impl EcosystemLog {
+    /// Record a new entry, dropping the oldest ones past the size limit
+    pub fn record(&mut self, day: u32, kind: LogEventKind, species: Option<&str>, message: String) {
+        self.entries.push(LogEntry {
+            day,
+            kind,
+            species: species.map(|name| name.to_string()),
+            message,
+        });
+
+        if self.entries.len() > MAX_LOG_ENTRIES {
+            let overflow = self.entries.len() - MAX_LOG_ENTRIES;
+            self.entries.drain(0..overflow);
+        }
+    }
+
+    /// Iterate entries matching the optional species and kind filters
+    pub fn filtered<'a>(
+        &'a self,
+        species: Option<&'a str>,
+        kind: Option<LogEventKind>,
+    ) -> impl Iterator<Item = &'a LogEntry> {
+        self.entries.iter().filter(move |entry| {
+            species.is_none_or(|name| entry.species.as_deref() == Some(name))
+                && kind.is_none_or(|k| entry.kind == k)
+        })
+    }
+
+    /// Group filtered entries by day, newest day first
+    pub fn grouped_by_day<'a>(&'a self, species: Option<&'a str>, kind: Option<LogEventKind>) -> Vec<(u32, Vec<&'a LogEntry>)> {
+        let mut groups: Vec<(u32, Vec<&'a LogEntry>)> = Vec::new();
+
+        for entry in self.filtered(species, kind) {
+            match groups.last_mut() {
+                Some((day, day_entries)) if *day == entry.day => day_entries.push(entry),
+                _ => groups.push((entry.day, vec![entry])),
+            }
+        }
+
+        groups.reverse();
+        groups
+    }
+
+    /// Sorted names of every species mentioned in the log
+    pub fn species_names(&self) -> Vec<String> {
+        self.entries
+            .iter()
+            .filter_map(|entry| entry.species.clone())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Record the notable lifecycle events of a simulated day
+    /// Feeding and matter events are too frequent to be useful to the player and are skipped
+    pub fn record_daily_result(&mut self, result: &DailyResult, species_by_id: &HashMap<CreatureId, &'static str>) {
+        let species_of = |id: &CreatureId| species_by_id.get(id).copied().unwrap_or("Unknown");
+
+        for event in &result.total_events {
+            match event {
+                LifecycleEvent::CreatureReproduced { parent_id, offspring_id } => {
+                    let species = species_of(parent_id);
+                    self.record(
+                        result.day,
+                        LogEventKind::Born,
+                        Some(species),
+                        format!("{} #{} reproduced (offspring #{})", species, parent_id, offspring_id),
+                    );
+                }
+                LifecycleEvent::CreatureGrew { creature_id } => {
+                    let species = species_of(creature_id);
+                    self.record(
+                        result.day,
+                        LogEventKind::Matured,
+                        Some(species),
+                        format!("{} #{} matured", species, creature_id),
+                    );
+                }
+                LifecycleEvent::CreatureDied { creature_id, cause } => {
+                    let species = species_of(creature_id);
+                    self.record(
+                        result.day,
+                        LogEventKind::Died,
+                        Some(species),
+                        format!("{} #{} {}", species, creature_id, death_description(cause)),
+                    );
+                }
+                LifecycleEvent::CohortReproduced { species, offspring, .. } => {
+                    self.record(
+                        result.day,
+                        LogEventKind::Born,
+                        Some(species),
+                        format!("{} {} were born", offspring, species),
+                    );
+                }
+                LifecycleEvent::CohortGrew { species, count, .. } => {
+                    self.record(
+                        result.day,
+                        LogEventKind::Matured,
+                        Some(species),
+                        format!("{} {} matured", count, species),
+                    );
+                }
+                LifecycleEvent::CohortDied { species, cause, count, .. } => {
+                    self.record(
+                        result.day,
+                        LogEventKind::Died,
+                        Some(species),
+                        format!("{} {} {}", count, species, death_description(cause)),
+                    );
+                }
+                LifecycleEvent::EcologicalEvent { description, .. } => {
+                    self.record(
+                        result.day,
+                        LogEventKind::Ecological,
+                        None,
+                        description.clone(),
+                    );
+                }
+                LifecycleEvent::VariantEmerged { creature_id, parent_species, species } => {
+                    self.record(
+                        result.day,
+                        LogEventKind::Born,
+                        Some(species),
+                        format!("{} #{} was born different enough from other {} to found the {}", parent_species, creature_id, parent_species, species),
+                    );
+                }
+                LifecycleEvent::DiseaseOutbreak { species, tile } => {
+                    self.record(
+                        result.day,
+                        LogEventKind::Ecological,
+                        Some(species),
+                        format!("Disease broke out among the {} at tile ({}, {})", species, tile.x, tile.y),
+                    );
+                }
+                LifecycleEvent::Infected { species, count } => {
+                    self.record(
+                        result.day,
+                        LogEventKind::Ecological,
+                        Some(species),
+                        format!("{} {} fell ill", count, species),
+                    );
+                }
+                LifecycleEvent::Recovered { species, count } => {
+                    self.record(
+                        result.day,
+                        LogEventKind::Ecological,
+                        Some(species),
+                        format!("{} {} recovered and are now immune", count, species),
+                    );
+                }
+                LifecycleEvent::StageReached { species, stage, count } => {
+                    self.record(
+                        result.day,
+                        LogEventKind::Matured,
+                        Some(species),
+                        format!("{} {} entered the {} stage", count, species, stage.name().to_lowercase()),
+                    );
+                }
+                LifecycleEvent::CreatureFed { .. }
+                | LifecycleEvent::CohortFed { .. }
+                | LifecycleEvent::Parasitized { .. }
+                | LifecycleEvent::MatterTransformed { .. } => {}
+            }
+        }
+
+        let summary = result.get_summary();
+        self.record(
+            result.day,
+            LogEventKind::DayPassed,
+            None,
+            format!(
+                "Day ended: {} births, {} deaths, {} creatures",
+                summary.births, summary.deaths, summary.final_population
+            ),
+        );
+    }
+}
+
+/// Player-facing wording for a death cause
+fn death_description(cause: &DeathCause) -> &'static str {
+    match cause {
+        DeathCause::NaturalAge => "died of old age",
+        DeathCause::Starvation => "starved",
+        DeathCause::EnvironmentalStress => "succumbed to environmental stress",
+        DeathCause::Predation => "was eaten",
+        DeathCause::Disaster => "perished in a disaster",
+        DeathCause::Disease => "died of disease",
+    }
+}
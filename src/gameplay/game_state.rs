@@ -1,11 +1,11 @@
-use bevy::prelude::*;
 use crate::gameplay::cards::{Deck, Hand};
 
 const INITIAL_HAND_SIZE: usize = 3;
 
 /// Core game state for managing available species, deck, and player hand
 /// This represents what species the player can potentially add to their garden
-#[derive(Resource, Clone)]
+#[derive(Clone)]
+#[cfg_attr(feature = "bevy", derive(bevy::prelude::Resource))]
 pub struct GameState {
     pub deck: Deck,
     pub hand: Hand,
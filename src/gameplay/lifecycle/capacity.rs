@@ -0,0 +1,122 @@
+use crate::gameplay::species::Species;
+use super::{GardenGrid, MatterType, DEFAULT_GRID_WIDTH, DEFAULT_GRID_HEIGHT};
+
+/// How a species' base carrying capacity (`Species::max_population`) scales with the garden
+#[derive(Clone, Debug)]
+pub enum CapacityScaling {
+    /// Use `max_population` as-is
+    Fixed,
+    /// Multiply by the garden's tile count relative to the default garden's
+    GardenSize,
+    /// Multiply by how much of a matter pool is available across the garden relative to a baseline amount
+    Resource { matter_type: MatterType, baseline_amount: u32 },
+}
+
+/// Soft density-dependent penalties applied as a species approaches its cap
+#[derive(Clone, Debug)]
+pub struct DensityPenalty {
+    /// Fraction of capacity at which penalties start (0.0 to 1.0)
+    pub onset_ratio: f32,
+    /// Feeding satisfaction lost at full capacity (0.0 to 1.0)
+    pub max_feeding_penalty: f32,
+    /// Days of starvation tolerance lost at full capacity
+    pub max_starvation_days_lost: u32,
+}
+
+impl Default for DensityPenalty {
+    fn default() -> Self {
+        Self {
+            onset_ratio: 0.75,
+            max_feeding_penalty: 0.3,
+            max_starvation_days_lost: 2,
+        }
+    }
+}
+
+/// Game-wide carrying capacity rules
+/// Reproduction is always suppressed at the cap; density penalties are optional
+#[derive(Clone, Debug)]
+pub struct CarryingCapacity {
+    pub scaling: CapacityScaling,
+    pub density_penalty: Option<DensityPenalty>,
+}
+
+impl Default for CarryingCapacity {
+    fn default() -> Self {
+        Self {
+            scaling: CapacityScaling::Fixed,
+            density_penalty: None,
+        }
+    }
+}
+
+impl CarryingCapacity {
+    /// Maximum living population for a species in the current garden
+    pub fn capacity_for(&self, species: &Species, grid: &GardenGrid) -> u32 {
+        let base = species.max_population as f32;
+
+        let scaled = match &self.scaling {
+            CapacityScaling::Fixed => base,
+            CapacityScaling::GardenSize => {
+                base * grid.tile_count() as f32 / (DEFAULT_GRID_WIDTH * DEFAULT_GRID_HEIGHT) as f32
+            }
+            CapacityScaling::Resource { matter_type, baseline_amount } => {
+                if *baseline_amount == 0 {
+                    base
+                } else {
+                    base * grid.total_amount(*matter_type) as f32 / *baseline_amount as f32
+                }
+            }
+        };
+
+        // A species that is allowed in the garden can always hold at least one individual
+        (scaled.floor() as u32).max(1)
+    }
+
+    /// How far past the penalty onset a population is (0.0 = no pressure, 1.0 = at or above cap)
+    pub fn density_pressure(&self, population: u32, capacity: u32) -> f32 {
+        let Some(penalty) = &self.density_penalty else {
+            return 0.0;
+        };
+
+        let ratio = population as f32 / capacity.max(1) as f32;
+        if ratio <= penalty.onset_ratio {
+            return 0.0;
+        }
+
+        let span = (1.0 - penalty.onset_ratio).max(f32::EPSILON);
+        ((ratio - penalty.onset_ratio) / span).min(1.0)
+    }
+
+    /// Multiplier applied to feeding satisfaction under density pressure
+    pub fn feeding_multiplier(&self, pressure: f32) -> f32 {
+        match &self.density_penalty {
+            Some(penalty) => 1.0 - penalty.max_feeding_penalty * pressure,
+            None => 1.0,
+        }
+    }
+
+    /// Days of starvation tolerance lost under density pressure
+    pub fn starvation_days_lost(&self, pressure: f32) -> u32 {
+        match &self.density_penalty {
+            Some(penalty) => (penalty.max_starvation_days_lost as f32 * pressure).round() as u32,
+            None => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::species::get_species;
+
+    #[test]
+    fn garden_size_scaling_follows_the_tile_count() {
+        let rabbit = get_species("Rabbit").unwrap();
+        let capacity = CarryingCapacity { scaling: CapacityScaling::GardenSize, density_penalty: None };
+
+        assert_eq!(capacity.capacity_for(rabbit, &GardenGrid::default()), rabbit.max_population);
+        assert_eq!(capacity.capacity_for(rabbit, &GardenGrid::new(6, 6)), rabbit.max_population * 4);
+        assert_eq!(capacity.capacity_for(rabbit, &GardenGrid::new(1, 1)), (rabbit.max_population / 9).max(1));
+    }
+}
@@ -0,0 +1,175 @@
+/// Length of each season in days
+pub const DAYS_PER_SEASON: u32 = 10;
+
+/// Seasons of the garden calendar, starting in spring on day 0
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Season::Spring => "Spring",
+            Season::Summer => "Summer",
+            Season::Autumn => "Autumn",
+            Season::Winter => "Winter",
+        }
+    }
+
+    pub fn all() -> [Season; 4] {
+        [Season::Spring, Season::Summer, Season::Autumn, Season::Winter]
+    }
+
+    /// Sunlight and rain on a clear day of this season
+    fn base_inputs(&self) -> DailyInputs {
+        let (sunlight, rain) = match self {
+            Season::Spring => (100, 12),
+            Season::Summer => (130, 6),
+            Season::Autumn => (80, 10),
+            Season::Winter => (50, 8),
+        };
+
+        DailyInputs {
+            sunlight,
+            rain,
+            evaporation: 0,
+            co2: 5,
+            o2: 5,
+        }
+    }
+
+    /// Relative odds of each weather type in this season, in `Weather::all()` order
+    fn weather_odds(&self) -> [u32; 5] {
+        match self {
+            Season::Spring => [50, 35, 5, 10, 0],
+            Season::Summer => [45, 15, 20, 0, 20],
+            Season::Autumn => [50, 35, 5, 10, 0],
+            Season::Winter => [55, 25, 0, 20, 0],
+        }
+    }
+}
+
+/// A day's weather, modifying the season's sunlight and rain
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Weather {
+    Clear,
+    Rain,
+    Drought,
+    ColdSnap,
+    HeatWave,
+}
+
+impl Weather {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Weather::Clear => "Clear",
+            Weather::Rain => "Rain",
+            Weather::Drought => "Drought",
+            Weather::ColdSnap => "Cold Snap",
+            Weather::HeatWave => "Heat Wave",
+        }
+    }
+
+    pub fn all() -> [Weather; 5] {
+        [Weather::Clear, Weather::Rain, Weather::Drought, Weather::ColdSnap, Weather::HeatWave]
+    }
+
+    /// Adjust a season's inputs for this weather
+    fn apply(&self, inputs: &mut DailyInputs) {
+        match self {
+            Weather::Clear => {}
+            Weather::Rain => {
+                inputs.sunlight = inputs.sunlight * 7 / 10;
+                inputs.rain += 15;
+            }
+            Weather::Drought => {
+                inputs.rain = 0;
+                inputs.evaporation = 5;
+            }
+            Weather::ColdSnap => {
+                inputs.sunlight /= 2;
+            }
+            Weather::HeatWave => {
+                inputs.sunlight = inputs.sunlight * 13 / 10;
+                inputs.rain = 0;
+                inputs.evaporation = 10;
+            }
+        }
+    }
+}
+
+/// Environmental resources delivered to the garden at the start of a day
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DailyInputs {
+    /// Sunlight available for the day (replaces yesterday's)
+    pub sunlight: u32,
+    /// Ground water added by precipitation
+    pub rain: u32,
+    /// Ground water lost to heat and dry air
+    pub evaporation: u32,
+    pub co2: u32,
+    pub o2: u32,
+}
+
+/// The garden's calendar and weather generator
+/// Weather is drawn per day from the seed, so a garden with the same seed always sees the same weather
+/// and the forecast is exactly what will happen
+#[derive(Clone, Debug)]
+pub struct Climate {
+    pub seed: u64,
+}
+
+impl Default for Climate {
+    fn default() -> Self {
+        Self::new(0x5EA5_0115)
+    }
+}
+
+impl Climate {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Season on a given day
+    pub fn season_on(&self, day: u32) -> Season {
+        Season::all()[((day / DAYS_PER_SEASON) % 4) as usize]
+    }
+
+    /// Day within the current season, starting at 1
+    pub fn day_of_season(&self, day: u32) -> u32 {
+        day % DAYS_PER_SEASON + 1
+    }
+
+    /// Weather on a given day
+    pub fn weather_on(&self, day: u32) -> Weather {
+        let day_seed = self.seed ^ (day as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        let mut rng = fastrand::Rng::with_seed(day_seed);
+
+        let odds = self.season_on(day).weather_odds();
+        let mut roll = rng.u32(0..odds.iter().sum::<u32>());
+        for (weather, chance) in Weather::all().into_iter().zip(odds) {
+            if roll < chance {
+                return weather;
+            }
+            roll -= chance;
+        }
+
+        Weather::Clear
+    }
+
+    /// Weather for the `days` days following `day`
+    pub fn forecast(&self, day: u32, days: u32) -> Vec<(u32, Weather)> {
+        (day + 1..=day + days).map(|d| (d, self.weather_on(d))).collect()
+    }
+
+    /// Resources the garden receives on a given day
+    pub fn daily_inputs(&self, day: u32) -> DailyInputs {
+        let mut inputs = self.season_on(day).base_inputs();
+        self.weather_on(day).apply(&mut inputs);
+        inputs
+    }
+}
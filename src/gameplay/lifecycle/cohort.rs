@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+use super::{IndividualCreature, CreatureBiomass, CreatureId, EcosystemMatter, GardenGrid, Genome, MaturityStage, TilePosition, HealthStatus};
+
+/// Width of the age buckets creatures are grouped into, in days
+pub const COHORT_AGE_BUCKET_DAYS: u32 = 5;
+
+/// Width of the trait buckets creatures are grouped into; members' traits are averaged within a bucket
+pub const COHORT_GENOME_BUCKET_WIDTH: f32 = 0.25;
+
+/// Gardens larger than this switch to cohort mode by default
+pub const DEFAULT_COHORT_THRESHOLD: u32 = 5_000;
+
+/// Identity of a cohort: creatures of one species, stage, age bucket, health and trait bucket on one tile
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CohortKey {
+    pub species: &'static str,
+    pub stage: MaturityStage,
+    pub age_bucket: u32,
+    pub tile: TilePosition,
+    pub health: HealthStatus,
+    /// Each trait in `Genome::trait_names()` order, in units of `COHORT_GENOME_BUCKET_WIDTH`
+    pub genome_bucket: [u8; 4],
+}
+
+/// A group of identical creatures simulated as one unit
+/// `member` describes every member (per-member biomass, age, hunger, environment, mean genome);
+/// its `id` identifies the cohort in lifecycle events and feeding allocation
+#[derive(Clone, Debug)]
+pub struct Cohort {
+    pub member: IndividualCreature,
+    pub count: u32,
+}
+
+impl Cohort {
+    pub fn new(member: IndividualCreature, count: u32) -> Self {
+        Self { member, count }
+    }
+
+    pub fn id(&self) -> CreatureId {
+        self.member.id
+    }
+
+    pub fn key(&self) -> CohortKey {
+        CohortKey {
+            species: self.member.species.name,
+            stage: self.member.maturity_stage,
+            age_bucket: self.member.age_days / COHORT_AGE_BUCKET_DAYS,
+            tile: self.member.tile,
+            health: self.member.health,
+            genome_bucket: genome_bucket(&self.member.genome),
+        }
+    }
+
+    /// Whether two cohorts can be merged without blurring reproduction cooldowns or heritable traits
+    /// Only members with similar traits share a cohort, so trait variation survives cohort mode
+    pub fn can_merge_with(&self, other: &Cohort) -> bool {
+        self.merge_key() == other.merge_key()
+    }
+
+    fn merge_key(&self) -> (CohortKey, Option<u32>) {
+        (self.key(), self.member.last_reproduction_day)
+    }
+
+    /// Total biomass held by all members
+    pub fn total_biomass(&self) -> CreatureBiomass {
+        CreatureBiomass::new(
+            self.member.biomass.plant_matter * self.count,
+            self.member.biomass.animal_matter * self.count,
+        )
+    }
+
+    /// Fold `count` creatures like `other` into this cohort
+    /// Biomass and traits are averaged per member; the indivisible remainder of biomass is shed as dead matter
+    /// Age, hunger and environmental stress take the worse of the two so merging never extends a life
+    pub fn absorb(&mut self, other: &IndividualCreature, count: u32, matter: &mut EcosystemMatter) {
+        let merged_count = self.count + count;
+        let plant_total = self.member.biomass.plant_matter * self.count + other.biomass.plant_matter * count;
+        let animal_total = self.member.biomass.animal_matter * self.count + other.biomass.animal_matter * count;
+
+        self.member.biomass = CreatureBiomass::new(plant_total / merged_count, animal_total / merged_count);
+        matter.add_matter(super::MatterType::DeadPlantMatter, plant_total % merged_count);
+        matter.add_matter(super::MatterType::DeadAnimalMatter, animal_total % merged_count);
+
+        self.member.age_days = self.member.age_days.max(other.age_days);
+        self.member.days_since_last_fed = self.member.days_since_last_fed.max(other.days_since_last_fed);
+        self.member.days_in_bad_environment = self.member.days_in_bad_environment.max(other.days_in_bad_environment);
+        self.member.genome = self.member.genome.blend(self.count, &other.genome, count);
+        self.count = merged_count;
+    }
+}
+
+/// Trait bucket of a genome; the mean of genomes in one bucket stays in that bucket
+fn genome_bucket(genome: &Genome) -> [u8; 4] {
+    genome.traits().map(|value| (value / COHORT_GENOME_BUCKET_WIDTH).round() as u8)
+}
+
+/// Merge cohorts that have come to share a key (e.g. after a juvenile cohort matures)
+/// Keeps the first cohort of each group, so iteration order stays deterministic
+pub fn merge_cohorts(cohorts: &mut Vec<Cohort>, grid: &mut GardenGrid) {
+    let mut merged: Vec<Cohort> = Vec::with_capacity(cohorts.len());
+    let mut index_by_key: HashMap<(CohortKey, Option<u32>), usize> = HashMap::with_capacity(cohorts.len());
+
+    for cohort in cohorts.drain(..) {
+        match index_by_key.get(&cohort.merge_key()) {
+            Some(&index) => merged[index].absorb(&cohort.member, cohort.count, grid.tile_mut(cohort.member.tile)),
+            None => {
+                index_by_key.insert(cohort.merge_key(), merged.len());
+                merged.push(cohort);
+            }
+        }
+    }
+
+    *cohorts = merged;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use crate::gameplay::lifecycle::EcosystemPopulation;
+    use crate::gameplay::species::get_species;
+
+    /// Adult rabbits on one tile, each ten generations of mutation away from the founders
+    fn mutated_rabbits(count: u32) -> EcosystemPopulation {
+        let rabbit = Arc::new(get_species("Rabbit").unwrap().clone());
+        let mut ecosystem = EcosystemPopulation { cohort_threshold: Some(count / 2), ..Default::default() };
+        let mut rng = fastrand::Rng::with_seed(7);
+
+        for _ in 0..count {
+            let id = ecosystem.add_mature_creature(rabbit.clone(), (3, 2));
+            let mut genome = Genome::default();
+            for _ in 0..10 {
+                genome = genome.mutated(&mut rng, ecosystem.genetics.mutation_rate);
+            }
+            ecosystem.creatures.get_mut(id).unwrap().genome = genome;
+        }
+
+        ecosystem
+    }
+
+    fn mean_traits(members: impl Iterator<Item = (Genome, u32)>) -> [f32; 4] {
+        let (mut sums, mut total) = ([0.0; 4], 0);
+        for (genome, count) in members {
+            for (sum, value) in sums.iter_mut().zip(genome.traits()) {
+                *sum += value * count as f32;
+            }
+            total += count;
+        }
+        sums.map(|sum| sum / total as f32)
+    }
+
+    #[test]
+    fn creatures_with_mutated_genomes_are_grouped_by_trait_bucket() {
+        let mut ecosystem = mutated_rabbits(5_000);
+        ecosystem.update_population_mode();
+
+        assert!(ecosystem.in_cohort_mode());
+        assert_eq!(ecosystem.cohorts.iter().map(|cohort| cohort.count).sum::<u32>(), 5_000);
+        // Only their traits set these rabbits apart, and ten generations of drift span a few buckets per trait
+        assert!(ecosystem.cohorts.len() <= 100, "{} cohorts", ecosystem.cohorts.len());
+        assert!(ecosystem.cohorts.len() > 1);
+    }
+
+    #[test]
+    fn cohorts_keep_their_members_mean_traits() {
+        let mut ecosystem = mutated_rabbits(1_000);
+        let before = mean_traits(ecosystem.creatures.iter().map(|creature| (creature.genome, 1)));
+
+        ecosystem.update_population_mode();
+        let after = mean_traits(ecosystem.cohorts.iter().map(|cohort| (cohort.member.genome, cohort.count)));
+
+        for (before, after) in before.into_iter().zip(after) {
+            assert!((before - after).abs() < 1e-3, "mean trait moved from {} to {}", before, after);
+        }
+    }
+
+    #[test]
+    fn distinct_trait_buckets_stay_apart() {
+        let mut ecosystem = mutated_rabbits(2);
+        let genomes = [Genome::default(), Genome { size: 1.5, ..Genome::default() }];
+        for (creature, genome) in ecosystem.creatures.iter_mut().zip(genomes) {
+            creature.genome = genome;
+        }
+        ecosystem.cohort_threshold = Some(0);
+        ecosystem.update_population_mode();
+
+        assert_eq!(ecosystem.cohorts.len(), 2);
+    }
+}
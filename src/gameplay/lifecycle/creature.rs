@@ -1,17 +1,37 @@
-use crate::gameplay::species::Species;
+use std::sync::Arc;
+use crate::gameplay::species::{Species, BiomassComposition, StageTraits, StageTrigger};
 use super::matter::MatterType;
+use super::grid::TilePosition;
+use super::genetics::{Genome, scale_trait};
 
 /// Unique identifier for individual creatures
 pub type CreatureId = u64;
 
 /// Maturity stages of a creature's lifecycle
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Which stages a species passes through, and what moves it on, is set by its `LifeStages`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum MaturityStage {
+    /// A seed, egg or spore resting until conditions let it germinate or hatch
+    Seed,
     Juvenile,
     Mature,
+    /// An aged adult past breeding, feeding less efficiently and more easily killed
+    Senescent,
     Dead,
 }
 
+impl MaturityStage {
+    pub fn name(&self) -> &'static str {
+        match self {
+            MaturityStage::Seed => "Seed",
+            MaturityStage::Juvenile => "Juvenile",
+            MaturityStage::Mature => "Mature",
+            MaturityStage::Senescent => "Senescent",
+            MaturityStage::Dead => "Dead",
+        }
+    }
+}
+
 /// Result of a creature's feeding attempt
 #[derive(Clone, Debug)]
 pub enum FeedingResult {
@@ -42,11 +62,44 @@ impl FeedingResult {
 }
 
 /// Causes of creature death for statistics tracking
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum DeathCause {
     NaturalAge,
     Starvation,
     EnvironmentalStress,
+    Predation,
+    Disaster,
+    Disease,
+}
+
+impl DeathCause {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DeathCause::NaturalAge => "Old Age",
+            DeathCause::Starvation => "Starvation",
+            DeathCause::EnvironmentalStress => "Environmental Stress",
+            DeathCause::Predation => "Predation",
+            DeathCause::Disaster => "Disaster",
+            DeathCause::Disease => "Disease",
+        }
+    }
+}
+
+/// A creature's history with disease
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum HealthStatus {
+    #[default]
+    Healthy,
+    /// Sick since the given day: feeds poorly, may die, and can pass the infection on
+    Infected { since_day: u32 },
+    /// Recovered from an infection and can't catch it again
+    Immune,
+}
+
+impl HealthStatus {
+    pub fn is_infected(&self) -> bool {
+        matches!(self, HealthStatus::Infected { .. })
+    }
 }
 
 /// Biomass composition of an individual creature
@@ -123,14 +176,22 @@ impl CreatureBiomass {
 #[derive(Clone, Debug)]
 pub struct IndividualCreature {
     pub id: CreatureId,
-    pub species: Species,
+    pub species: Arc<Species>, // Shared between individuals so large populations stay cheap to copy
     pub maturity_stage: MaturityStage,
     pub age_days: u32,
     pub days_since_last_fed: u32,
     pub days_in_bad_environment: u32,
+    pub in_growth_environment: bool,
+    pub in_reproduction_environment: bool,
+    pub dormant: bool, // Sitting out the season: no feeding, growth, breeding, hunger or stress
     pub last_reproduction_day: Option<u32>,
     pub fed_status: FeedingResult,
+    pub fed_on_day: Option<u32>, // Day the feeding status was last set
     pub biomass: CreatureBiomass,
+    pub death_cause: Option<DeathCause>,
+    pub tile: TilePosition, // Where in the garden grid the creature lives and feeds
+    pub genome: Genome, // Heritable variation on the species' parameters
+    pub health: HealthStatus, // Infection and immunity; offspring are always born healthy
     
     // Tracking for ecosystem statistics
     pub introduction_day: u32,  // When creature was added to ecosystem
@@ -141,21 +202,30 @@ impl IndividualCreature {
     /// Create a new juvenile creature
     pub fn new(
         id: CreatureId,
-        species: Species,
+        species: impl Into<Arc<Species>>,
         initial_biomass: CreatureBiomass,
         introduction_day: u32,
         introduction_order: u32,
     ) -> Self {
+        let species: Arc<Species> = species.into();
         Self {
             id,
+            maturity_stage: species.life_stages.birth_stage,
             species,
-            maturity_stage: MaturityStage::Juvenile,
             age_days: 0,
             days_since_last_fed: 0,
             days_in_bad_environment: 0,
+            in_growth_environment: true,
+            in_reproduction_environment: true,
+            dormant: false,
             last_reproduction_day: None,
             fed_status: FeedingResult::Starving,
+            fed_on_day: None,
             biomass: initial_biomass,
+            death_cause: None,
+            tile: TilePosition::default(),
+            genome: Genome::default(),
+            health: HealthStatus::default(),
             introduction_day,
             introduction_order,
         }
@@ -164,7 +234,7 @@ impl IndividualCreature {
     /// Create a new mature creature (for breeding pairs, etc.)
     pub fn new_mature(
         id: CreatureId,
-        species: Species,
+        species: impl Into<Arc<Species>>,
         initial_biomass: CreatureBiomass,
         introduction_day: u32,
         introduction_order: u32,
@@ -179,9 +249,9 @@ impl IndividualCreature {
         self.maturity_stage != MaturityStage::Dead
     }
 
-    /// Check if creature is mature
+    /// Check if creature is mature (senescent creatures are still adults)
     pub fn is_mature(&self) -> bool {
-        self.maturity_stage == MaturityStage::Mature
+        matches!(self.maturity_stage, MaturityStage::Mature | MaturityStage::Senescent)
     }
 
     /// Check if creature is juvenile
@@ -194,7 +264,10 @@ impl IndividualCreature {
         if self.is_alive() {
             self.age_days += 1;
             
-            // Update feeding counter
+            // Update feeding counter (paused while dormant)
+            if self.dormant {
+                return;
+            }
             if !self.fed_status.is_fed() {
                 self.days_since_last_fed += 1;
             } else {
@@ -211,10 +284,38 @@ impl IndividualCreature {
         self.fed_status = result;
     }
 
-    /// Attempt to mature from juvenile to adult
+    /// How the creature's current life stage behaves
+    pub fn stage_traits(&self) -> StageTraits {
+        self.species.life_stages.traits(self.maturity_stage)
+    }
+
+    /// Whether the creature eats today: it is awake and in a life stage that feeds (seeds and eggs don't)
+    pub fn feeds(&self) -> bool {
+        !self.dormant && self.stage_traits().feeding_multiplier > 0.0
+    }
+
+    /// The stage the creature is ready to move on to today, if any of its species' transitions are met
+    pub fn next_stage(&self) -> Option<MaturityStage> {
+        self.species.life_stages
+            .transitions_from(self.maturity_stage)
+            .find(|transition| match transition.trigger {
+                StageTrigger::WellFed { threshold } => {
+                    self.age_days >= self.species.growth_requirements.minimum_age
+                        && !self.dormant
+                        && self.in_growth_environment
+                        && self.fed_status.is_well_fed(threshold)
+                }
+                // Resting seeds still sense their tile, so germination is checked whether or not they're dormant
+                StageTrigger::Germinates { min_days } => self.age_days >= min_days && self.in_growth_environment,
+                StageTrigger::Age { days } => self.age_days >= scale_trait(days, self.genome.lifespan),
+            })
+            .map(|transition| transition.to)
+    }
+
+    /// Attempt to move on to the next life stage
     pub fn attempt_growth(&mut self) -> bool {
-        if self.is_juvenile() && self.fed_status.is_well_fed(0.7) {
-            self.maturity_stage = MaturityStage::Mature;
+        if let Some(stage) = self.next_stage() {
+            self.maturity_stage = stage;
             true
         } else {
             false
@@ -222,16 +323,35 @@ impl IndividualCreature {
     }
 
     /// Mark creature as dead with cause
-    pub fn die(&mut self, _cause: DeathCause) {
+    pub fn die(&mut self, cause: DeathCause) {
         self.maturity_stage = MaturityStage::Dead;
+        self.death_cause = Some(cause);
     }
 
     /// Get the creature's biomass as dead matter when it dies
-    pub fn get_death_matter(&self) -> (MatterType, u32) {
-        match self.species.kingdom {
-            crate::gameplay::species::Kingdom::Plant | 
-            crate::gameplay::species::Kingdom::Fungi => (MatterType::DeadPlantMatter, self.biomass.plant_matter),
-            crate::gameplay::species::Kingdom::Animal => (MatterType::DeadAnimalMatter, self.biomass.animal_matter),
-        }
+    /// Every component is returned: plant matter becomes dead plant matter and animal matter dead animal matter
+    pub fn get_death_matter(&self) -> [(MatterType, u32); 2] {
+        [
+            (MatterType::DeadPlantMatter, self.biomass.plant_matter),
+            (MatterType::DeadAnimalMatter, self.biomass.animal_matter),
+        ]
+    }
+
+    /// Add biomass the creature built from food as `built_type`, or for mixed-composition species
+    /// as whichever components bring its biomass closest to the species' ratios; returns how much of each component was added
+    pub fn build_biomass(&mut self, built_type: MatterType, amount: u32) -> [(MatterType, u32); 2] {
+        let (plant_matter, animal_matter) = match self.species.biomass_composition {
+            // Steering towards the ratios keeps small meals from always rounding into the larger component
+            BiomassComposition::Mixed { .. } => {
+                let (target_plant, _) = self.species.biomass_composition.split(self.biomass.total() + amount);
+                let plant_matter = target_plant.saturating_sub(self.biomass.plant_matter).min(amount);
+                (plant_matter, amount - plant_matter)
+            }
+            _ if built_type == MatterType::PlantMatter => (amount, 0),
+            _ => (0, amount),
+        };
+        self.biomass.add_matter(MatterType::PlantMatter, plant_matter);
+        self.biomass.add_matter(MatterType::AnimalMatter, animal_matter);
+        [(MatterType::PlantMatter, plant_matter), (MatterType::AnimalMatter, animal_matter)]
     }
 }
@@ -2,8 +2,9 @@
 
 use super::{
     LifecyclePhase, PhaseResult, PhaseError, LifecycleEvent,
-    EcosystemPopulation, DeathCause, MaturityStage
+    EcosystemPopulation, DeathCause, MatterType, scale_trait
 };
+use super::invariants;
 
 /// Death phase - removes creatures that have died and converts their biomass
 /// Processes all death conditions before any other lifecycle phases
@@ -15,7 +16,7 @@ impl DeathPhase {
     }
 
     /// Check if a creature should die from various causes
-    fn check_death_conditions(&self, creature: &crate::gameplay::lifecycle::IndividualCreature) -> Option<DeathCause> {
+    fn check_death_conditions(&self, creature: &crate::gameplay::lifecycle::IndividualCreature, starvation_tolerance: u32) -> Option<DeathCause> {
         // Already dead
         if !creature.is_alive() {
             return None;
@@ -25,28 +26,53 @@ impl DeathPhase {
         // For now, use simple rules:
 
         // Starvation death
-        if creature.days_since_last_fed > 3 {
+        if creature.days_since_last_fed > starvation_tolerance {
             return Some(DeathCause::Starvation);
         }
 
-        // Natural aging (simplified)
-        let max_age = match creature.species.kingdom {
+        // Natural aging (simplified), stretched or shortened by the creature's lifespan trait
+        let species_max_age = match creature.species.kingdom {
             crate::gameplay::species::Kingdom::Plant => 100,    // Plants live longer
             crate::gameplay::species::Kingdom::Animal => 50,    // Animals have shorter lives
             crate::gameplay::species::Kingdom::Fungi => 75,     // Fungi intermediate
         };
+        let max_age = scale_trait(species_max_age, creature.genome.lifespan);
 
         if creature.age_days > max_age {
             return Some(DeathCause::NaturalAge);
         }
 
-        // Environmental stress (simplified)
-        if creature.days_in_bad_environment > 10 {
+        // Environmental stress beyond the species' tolerance
+        if creature.days_in_bad_environment > creature.species.mortality_factors.environmental_tolerance {
             return Some(DeathCause::EnvironmentalStress);
         }
 
         None
     }
+
+    /// Days a creature can go without food, scaled up by its size and down by its life stage's vulnerability
+    fn starvation_tolerance(creature: &crate::gameplay::lifecycle::IndividualCreature) -> u32 {
+        let tolerance = scale_trait(creature.species.mortality_factors.starvation_tolerance, creature.genome.size);
+        let vulnerability = creature.stage_traits().vulnerability;
+        if vulnerability <= 0.0 {
+            return u32::MAX;
+        }
+        (tolerance as f32 / vulnerability).round() as u32
+    }
+
+    /// Log each component of the dead creatures' biomass turning into dead matter
+    fn log_remains(remains: [(MatterType, u32); 2], count: u32, events: &mut Vec<LifecycleEvent>) {
+        let living_types = [MatterType::PlantMatter, MatterType::AnimalMatter];
+        for (from_type, (to_type, amount_per_member)) in living_types.into_iter().zip(remains) {
+            if amount_per_member > 0 {
+                events.push(LifecycleEvent::MatterTransformed {
+                    from_type,
+                    to_type,
+                    amount: amount_per_member * count,
+                });
+            }
+        }
+    }
 }
 
 impl LifecyclePhase for DeathPhase {
@@ -61,7 +87,12 @@ impl LifecyclePhase for DeathPhase {
         for creature in ecosystem.living_creatures() {
             creatures_processed += 1;
 
-            if let Some(death_cause) = self.check_death_conditions(creature) {
+            // Larger creatures last longer without food; crowded species and vulnerable life stages tolerate less hunger
+            let pressure = ecosystem.density_pressure(&creature.species);
+            let starvation_tolerance = Self::starvation_tolerance(creature)
+                .saturating_sub(ecosystem.carrying_capacity.starvation_days_lost(pressure));
+
+            if let Some(death_cause) = self.check_death_conditions(creature, starvation_tolerance) {
                 creatures_to_kill.push((creature.id, death_cause));
             }
         }
@@ -69,7 +100,7 @@ impl LifecyclePhase for DeathPhase {
         // Apply death to marked creatures
         for (creature_id, death_cause) in creatures_to_kill {
             // Mark creature as dead
-            if let Some(creature) = ecosystem.creatures.iter_mut().find(|c| c.id == creature_id) {
+            if let Some(creature) = ecosystem.creatures.get_mut(creature_id) {
                 let biomass_amount = creature.biomass.total();
                 creature.die(death_cause.clone());
                 matter_transformed += biomass_amount;
@@ -82,24 +113,51 @@ impl LifecyclePhase for DeathPhase {
         }
 
         // Remove dead creatures and convert their biomass to dead matter
-        let removed_creatures = ecosystem.remove_dead_creatures();
+        let removed_creatures = ecosystem.remove_dead_creatures(self.phase_name())?;
         
         // Log matter transformations
         for (creature, _cause) in &removed_creatures {
-            let (dead_matter_type, amount) = creature.get_death_matter();
-            if amount > 0 {
-                events.push(LifecycleEvent::MatterTransformed {
-                    from_type: match creature.species.kingdom {
-                        crate::gameplay::species::Kingdom::Plant | 
-                        crate::gameplay::species::Kingdom::Fungi => super::MatterType::PlantMatter,
-                        crate::gameplay::species::Kingdom::Animal => super::MatterType::AnimalMatter,
-                    },
-                    to_type: dead_matter_type,
-                    amount,
-                });
+            Self::log_remains(creature.get_death_matter(), 1, &mut events);
+        }
+
+        // Cohorts share one state, so a whole cohort dies together
+        let mut cohorts_to_kill = Vec::new();
+        for cohort in &ecosystem.cohorts {
+            creatures_processed += cohort.count;
+
+            let pressure = ecosystem.density_pressure(&cohort.member.species);
+            let starvation_tolerance = Self::starvation_tolerance(&cohort.member)
+                .saturating_sub(ecosystem.carrying_capacity.starvation_days_lost(pressure));
+
+            if let Some(death_cause) = self.check_death_conditions(&cohort.member, starvation_tolerance) {
+                cohorts_to_kill.push((cohort.id(), death_cause));
             }
         }
 
+        for (cohort_id, death_cause) in cohorts_to_kill {
+            let Some(index) = ecosystem.cohorts.iter().position(|cohort| cohort.id() == cohort_id) else {
+                continue;
+            };
+            let cohort = ecosystem.cohorts.remove(index);
+            let remains = cohort.member.get_death_matter();
+
+            for (dead_matter_type, amount_per_member) in remains {
+                if amount_per_member > 0 {
+                    ecosystem.grid.tile_mut(cohort.member.tile).add_matter(dead_matter_type, amount_per_member * cohort.count);
+                }
+            }
+            ecosystem.record_deaths(cohort.member.species.name, &death_cause, cohort.count);
+            matter_transformed += cohort.total_biomass().total();
+
+            events.push(LifecycleEvent::CohortDied {
+                cohort_id,
+                species: cohort.member.species.name,
+                cause: death_cause,
+                count: cohort.count,
+            });
+            Self::log_remains(remains, cohort.count, &mut events);
+        }
+
         Ok(PhaseResult {
             creatures_processed,
             matter_transformed,
@@ -114,15 +172,8 @@ impl LifecyclePhase for DeathPhase {
 
     fn validate_postconditions(&self, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
         // Validate that no dead creatures remain in the population
-        for creature in &ecosystem.creatures {
-            if creature.maturity_stage == MaturityStage::Dead {
-                return Err(PhaseError::SystemError(
-                    "Dead creature found in population after death phase".to_string()
-                ));
-            }
-        }
-
-        Ok(())
+        invariants::check_no_dead_creatures(self.phase_name(), ecosystem)?;
+        invariants::check_population_invariants(self.phase_name(), ecosystem)
     }
 
     fn phase_name(&self) -> &'static str {
@@ -0,0 +1,105 @@
+use super::{
+    LifecyclePhase, PhaseResult, PhaseError, LifecycleEvent,
+    EcosystemPopulation, MatterType
+};
+use super::invariants;
+
+/// Matter types that break down on their own, and what each breaks down into
+/// Uneaten fruit rots after dead matter, so it lies a day as dead plant matter before becoming nutrients
+const DECAYING_MATTER: [(MatterType, MatterType); 3] = [
+    (MatterType::DeadPlantMatter, MatterType::SoilNutrients),
+    (MatterType::DeadAnimalMatter, MatterType::SoilNutrients),
+    (MatterType::Fruit, MatterType::DeadPlantMatter),
+];
+
+/// How quickly dead matter left lying in the garden breaks down into soil nutrients without help from decomposers;
+/// may be changed between rounds
+#[derive(Clone, Debug)]
+pub struct Decay {
+    /// Fraction of each tile's dead plant and dead animal matter that becomes soil nutrients each day,
+    /// and of its uneaten fruit that rots into dead plant matter
+    pub rate: f32,
+}
+
+impl Default for Decay {
+    fn default() -> Self {
+        Self { rate: 0.02 }
+    }
+}
+
+impl Decay {
+    pub fn with_rate(mut self, rate: f32) -> Self {
+        self.rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// How much of a pool of dead matter decays today
+    /// Any non-empty pool loses at least one unit, so small heaps don't lie in the garden forever
+    pub fn decayed(&self, amount: u32) -> u32 {
+        if self.rate <= 0.0 {
+            return 0;
+        }
+        ((amount as f32 * self.rate).ceil() as u32).min(amount)
+    }
+}
+
+/// Decay phase - dead matter slowly breaks down into soil nutrients, and uneaten fruit rots
+/// Runs last, so decomposers get the first pick of the day's dead matter and waste, and plants get to sow their fruit
+pub struct DecayPhase;
+
+impl DecayPhase {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl LifecyclePhase for DecayPhase {
+    fn execute(&self, ecosystem: &mut EcosystemPopulation) -> Result<PhaseResult, PhaseError> {
+        let decay = ecosystem.decay.clone();
+        let mut decayed_by_type = [0; DECAYING_MATTER.len()];
+
+        for tile in ecosystem.grid.tiles_mut() {
+            for ((from_type, to_type), total) in DECAYING_MATTER.iter().zip(decayed_by_type.iter_mut()) {
+                let decayed = tile.consume_matter(*from_type, decay.decayed(tile.get_amount(*from_type)));
+                tile.add_matter(*to_type, decayed);
+                *total += decayed;
+            }
+        }
+
+        // Decay is reported for the whole garden rather than tile by tile
+        let events = DECAYING_MATTER.iter()
+            .zip(decayed_by_type)
+            .filter(|(_, amount)| *amount > 0)
+            .map(|((from_type, to_type), amount)| LifecycleEvent::MatterTransformed {
+                from_type: *from_type,
+                to_type: *to_type,
+                amount,
+            })
+            .collect();
+
+        Ok(PhaseResult {
+            creatures_processed: 0,
+            matter_transformed: decayed_by_type.iter().sum(),
+            events,
+        })
+    }
+
+    fn validate_preconditions(&self, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+        invariants::check_population_invariants(self.phase_name(), ecosystem)
+    }
+
+    fn validate_postconditions(&self, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+        // Decay only moves matter between pools
+        invariants::check_population_invariants(self.phase_name(), ecosystem)
+    }
+
+    fn phase_name(&self) -> &'static str {
+        "Decay"
+    }
+}
+
+impl Default for DecayPhase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,300 @@
+use std::collections::HashMap;
+use super::{
+    LifecyclePhase, PhaseResult, PhaseError, LifecycleEvent,
+    EcosystemPopulation, DeathCause, HealthStatus, CreatureId, TilePosition
+};
+use super::invariants;
+
+/// How infections appear, spread, weaken and kill; may be changed between rounds
+/// Infections are drawn per day from the seed, so a seeded garden sees the same outbreaks every time
+#[derive(Clone, Debug)]
+pub struct Disease {
+    pub seed: u64,
+    /// Members of one species on one tile before an infection can appear among them
+    pub outbreak_density: u32,
+    /// Daily chance of an infection appearing in each species crowded onto a tile
+    pub outbreak_chance: f32,
+    /// Daily chance that each infected creature passes the infection to a healthy one of its species on its tile
+    pub transmission_rate: f32,
+    /// Fraction of its feeding efficiency an infected creature loses
+    pub feeding_penalty: f32,
+    /// Daily chance that an infected creature dies
+    pub daily_mortality: f32,
+    /// Days an infection lasts before survivors recover for good
+    pub duration_days: u32,
+}
+
+impl Default for Disease {
+    fn default() -> Self {
+        Self::new(0x5EC_7E5E)
+    }
+}
+
+impl Disease {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            outbreak_density: 12,
+            outbreak_chance: 0.02,
+            transmission_rate: 0.02,
+            feeding_penalty: 0.5,
+            daily_mortality: 0.08,
+            duration_days: 6,
+        }
+    }
+
+    pub fn with_outbreak_chance(mut self, chance: f32) -> Self {
+        self.outbreak_chance = chance.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn with_transmission_rate(mut self, rate: f32) -> Self {
+        self.transmission_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Random number generator for a given day's infections
+    fn rng_for(&self, day: u32) -> fastrand::Rng {
+        fastrand::Rng::with_seed(self.seed ^ (day as u64).wrapping_mul(0x94D0_49BB_1331_11EB))
+    }
+
+    /// Multiplier on a creature's feeding efficiency for its health
+    pub fn feeding_multiplier(&self, health: &HealthStatus) -> f32 {
+        if health.is_infected() {
+            1.0 - self.feeding_penalty
+        } else {
+            1.0
+        }
+    }
+
+    /// Chance that a healthy creature catches the infection from `infected` sick neighbours of its species
+    /// Each contact is a separate chance, so crowded, sick tiles are the most dangerous
+    fn infection_chance(&self, susceptibility: f32, infected: u32) -> f32 {
+        1.0 - (1.0 - self.transmission_rate * susceptibility).powi(infected as i32)
+    }
+}
+
+/// Round `count * chance` up or down at random, keeping the expected value
+fn share(count: u32, chance: f32, rng: &mut fastrand::Rng) -> u32 {
+    let expected = count as f32 * chance;
+    let whole = expected.floor();
+    (whole as u32 + u32::from(rng.f32() < expected - whole)).min(count)
+}
+
+/// Disease phase - infections end, kill, spread and break out
+/// Runs before the death phase, like ecological events, so the day's disease deaths are counted with the rest
+pub struct DiseasePhase;
+
+impl DiseasePhase {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Infections that have run their course end in immunity; the rest may kill, vulnerable life stages more readily
+    fn progress_infections(&self, ecosystem: &mut EcosystemPopulation, rng: &mut fastrand::Rng, events: &mut Vec<LifecycleEvent>) -> Result<u32, PhaseError> {
+        let day = ecosystem.current_day;
+        let (duration, mortality) = (ecosystem.disease.duration_days, ecosystem.disease.daily_mortality);
+        let mut recovered: HashMap<&'static str, u32> = HashMap::new();
+        let mut affected = 0;
+
+        for creature in ecosystem.living_creatures_mut() {
+            let HealthStatus::Infected { since_day } = creature.health else {
+                continue;
+            };
+            affected += 1;
+            if day >= since_day + duration {
+                creature.health = HealthStatus::Immune;
+                *recovered.entry(creature.species.name).or_insert(0) += 1;
+            } else if rng.f32() < mortality * creature.stage_traits().vulnerability {
+                creature.die(DeathCause::Disease);
+                events.push(LifecycleEvent::CreatureDied { creature_id: creature.id, cause: DeathCause::Disease });
+            }
+        }
+        ecosystem.remove_dead_creatures(self.phase_name())?;
+
+        for index in 0..ecosystem.cohorts.len() {
+            let cohort = &mut ecosystem.cohorts[index];
+            let HealthStatus::Infected { since_day } = cohort.member.health else {
+                continue;
+            };
+            affected += cohort.count;
+            if day >= since_day + duration {
+                cohort.member.health = HealthStatus::Immune;
+                *recovered.entry(cohort.member.species.name).or_insert(0) += cohort.count;
+                continue;
+            }
+
+            let dead = share(cohort.count, (mortality * cohort.member.stage_traits().vulnerability).min(1.0), rng);
+            if dead == 0 {
+                continue;
+            }
+            cohort.count -= dead;
+            let remains = cohort.member.get_death_matter();
+            let (cohort_id, species, tile) = (cohort.id(), cohort.member.species.name, cohort.member.tile);
+
+            for (dead_matter_type, amount_per_member) in remains {
+                ecosystem.grid.tile_mut(tile).add_matter(dead_matter_type, amount_per_member * dead);
+            }
+            ecosystem.record_deaths(species, &DeathCause::Disease, dead);
+            events.push(LifecycleEvent::CohortDied { cohort_id, species, cause: DeathCause::Disease, count: dead });
+        }
+        ecosystem.cohorts.retain(|cohort| cohort.count > 0);
+
+        let mut recovered: Vec<_> = recovered.into_iter().collect();
+        recovered.sort();
+        events.extend(recovered.into_iter().map(|(species, count)| LifecycleEvent::Recovered { species, count }));
+        Ok(affected)
+    }
+
+    /// Sick creatures infect healthy members of their species on the same tile
+    fn spread(&self, ecosystem: &mut EcosystemPopulation, rng: &mut fastrand::Rng, infected_today: &mut HashMap<&'static str, u32>) {
+        let mut sick: HashMap<(&'static str, TilePosition), u32> = HashMap::new();
+        for creature in ecosystem.living_creatures().filter(|c| c.health.is_infected()) {
+            *sick.entry((creature.species.name, creature.tile)).or_insert(0) += 1;
+        }
+        for cohort in ecosystem.cohorts.iter().filter(|c| c.member.health.is_infected()) {
+            *sick.entry((cohort.member.species.name, cohort.member.tile)).or_insert(0) += cohort.count;
+        }
+        if sick.is_empty() {
+            return;
+        }
+
+        let day = ecosystem.current_day;
+        let disease = ecosystem.disease.clone();
+        for creature in ecosystem.living_creatures_mut() {
+            if creature.health != HealthStatus::Healthy {
+                continue;
+            }
+            let Some(&infected) = sick.get(&(creature.species.name, creature.tile)) else {
+                continue;
+            };
+            if rng.f32() < disease.infection_chance(creature.species.mortality_factors.disease_susceptibility, infected) {
+                creature.health = HealthStatus::Infected { since_day: day };
+                *infected_today.entry(creature.species.name).or_insert(0) += 1;
+            }
+        }
+
+        let cohort_count = ecosystem.cohorts.len();
+        for index in 0..cohort_count {
+            let cohort = &ecosystem.cohorts[index];
+            if cohort.member.health != HealthStatus::Healthy {
+                continue;
+            }
+            let Some(&infected) = sick.get(&(cohort.member.species.name, cohort.member.tile)) else {
+                continue;
+            };
+            let chance = disease.infection_chance(cohort.member.species.mortality_factors.disease_susceptibility, infected);
+            let caught = share(cohort.count, chance, rng);
+            if caught > 0 {
+                *infected_today.entry(cohort.member.species.name).or_insert(0) += caught;
+                self.infect_cohort_members(ecosystem, index, caught, day);
+            }
+        }
+    }
+
+    /// Split `count` members off a healthy cohort into an infected one
+    fn infect_cohort_members(&self, ecosystem: &mut EcosystemPopulation, index: usize, count: u32, day: u32) {
+        let cohort = &ecosystem.cohorts[index];
+        if count >= cohort.count {
+            ecosystem.cohorts[index].member.health = HealthStatus::Infected { since_day: day };
+            return;
+        }
+
+        let mut sick = cohort.clone();
+        sick.member.id = ecosystem.allocate_creature_id();
+        sick.member.health = HealthStatus::Infected { since_day: day };
+        sick.count = count;
+        ecosystem.cohorts[index].count -= count;
+        ecosystem.cohorts.push(sick);
+    }
+
+    /// An infection may appear in any species crowded onto a tile
+    fn outbreaks(&self, ecosystem: &mut EcosystemPopulation, rng: &mut fastrand::Rng, events: &mut Vec<LifecycleEvent>, infected_today: &mut HashMap<&'static str, u32>) {
+        let mut crowds: HashMap<(&'static str, TilePosition), (u32, f32)> = HashMap::new();
+        let members = ecosystem.living_creatures()
+            .map(|creature| (creature, 1))
+            .chain(ecosystem.cohorts.iter().map(|cohort| (&cohort.member, cohort.count)));
+        for (creature, count) in members {
+            let crowd = crowds.entry((creature.species.name, creature.tile))
+                .or_insert((0, creature.species.mortality_factors.disease_susceptibility));
+            crowd.0 += count;
+        }
+
+        let density = ecosystem.disease.outbreak_density;
+        let mut crowded: Vec<_> = crowds.into_iter()
+            .filter(|(_, (count, susceptibility))| *count >= density && *susceptibility > 0.0)
+            .collect();
+        crowded.sort_by_key(|((species, tile), _)| (*species, *tile));
+
+        let day = ecosystem.current_day;
+        for ((species, tile), (_, susceptibility)) in crowded {
+            if rng.f32() >= ecosystem.disease.outbreak_chance * susceptibility {
+                continue;
+            }
+
+            // Patient zero is a random healthy individual, or else one member of a healthy cohort
+            let healthy: Vec<CreatureId> = ecosystem.creatures_by_species(species)
+                .filter(|c| c.tile == tile && c.health == HealthStatus::Healthy)
+                .map(|c| c.id)
+                .collect();
+            if !healthy.is_empty() {
+                let patient = healthy[rng.usize(..healthy.len())];
+                if let Some(creature) = ecosystem.creatures.get_mut(patient) {
+                    creature.health = HealthStatus::Infected { since_day: day };
+                }
+            } else if let Some(index) = ecosystem.cohorts.iter().position(|cohort| {
+                cohort.member.species.name == species && cohort.member.tile == tile && cohort.member.health == HealthStatus::Healthy
+            }) {
+                self.infect_cohort_members(ecosystem, index, 1, day);
+            } else {
+                continue;
+            }
+
+            *infected_today.entry(species).or_insert(0) += 1;
+            events.push(LifecycleEvent::DiseaseOutbreak { species, tile });
+        }
+    }
+}
+
+impl LifecyclePhase for DiseasePhase {
+    fn execute(&self, ecosystem: &mut EcosystemPopulation) -> Result<PhaseResult, PhaseError> {
+        let mut events = Vec::new();
+        let mut rng = ecosystem.disease.rng_for(ecosystem.current_day);
+        let mut infected_today: HashMap<&'static str, u32> = HashMap::new();
+
+        // Yesterday's infections run their course before today's spread, so new cases can't die on their first day
+        let creatures_processed = self.progress_infections(ecosystem, &mut rng, &mut events)?;
+        self.spread(ecosystem, &mut rng, &mut infected_today);
+        self.outbreaks(ecosystem, &mut rng, &mut events, &mut infected_today);
+
+        let mut infected_today: Vec<_> = infected_today.into_iter().collect();
+        infected_today.sort();
+        events.extend(infected_today.into_iter().map(|(species, count)| LifecycleEvent::Infected { species, count }));
+
+        Ok(PhaseResult {
+            creatures_processed,
+            matter_transformed: 0,
+            events,
+        })
+    }
+
+    fn validate_preconditions(&self, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+        invariants::check_population_invariants(self.phase_name(), ecosystem)
+    }
+
+    fn validate_postconditions(&self, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+        // Disease deaths must be removed and counted before the death phase runs
+        invariants::check_no_dead_creatures(self.phase_name(), ecosystem)?;
+        invariants::check_population_invariants(self.phase_name(), ecosystem)
+    }
+
+    fn phase_name(&self) -> &'static str {
+        "Disease"
+    }
+}
+
+impl Default for DiseasePhase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
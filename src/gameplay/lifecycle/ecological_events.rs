@@ -0,0 +1,443 @@
+use super::{
+    LifecyclePhase, PhaseResult, PhaseError, LifecycleEvent,
+    EcosystemPopulation, MatterType, DeathCause, CreatureId, TilePosition
+};
+use super::invariants;
+use crate::gameplay::species::{get_all_species, Kingdom};
+
+/// Ground water a flood adds to each tile
+const FLOOD_WATER: u32 = 80;
+/// Soil nutrients a windfall adds to the tile it lands on
+const WINDFALL_NUTRIENTS: u32 = 40;
+/// Mature creatures arriving with an invasive species
+const INVASIVE_ARRIVALS: u32 = 3;
+
+/// Random events that can strike the garden at the start of a day
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EcologicalEventKind {
+    /// Burns half of every plant's biomass into ash (soil nutrients) and dead plant matter
+    Wildfire,
+    /// Soaks every tile and washes away half of its soil nutrients and dead matter
+    Flood,
+    /// Kills half of one species
+    Blight,
+    /// A few mature creatures of a species arrive on one tile from outside the garden
+    InvasiveSpecies,
+    /// Enriches one tile's soil with nutrients
+    NutrientWindfall,
+}
+
+impl EcologicalEventKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            EcologicalEventKind::Wildfire => "Wildfire",
+            EcologicalEventKind::Flood => "Flood",
+            EcologicalEventKind::Blight => "Blight",
+            EcologicalEventKind::InvasiveSpecies => "Invasive Species",
+            EcologicalEventKind::NutrientWindfall => "Nutrient Windfall",
+        }
+    }
+
+    pub fn all() -> [EcologicalEventKind; 5] {
+        [
+            EcologicalEventKind::Wildfire,
+            EcologicalEventKind::Flood,
+            EcologicalEventKind::Blight,
+            EcologicalEventKind::InvasiveSpecies,
+            EcologicalEventKind::NutrientWindfall,
+        ]
+    }
+}
+
+/// Chance of each event striking on any given day (0.0 to 1.0)
+/// At most one event happens per day, so the chances should add up to no more than 1.0
+#[derive(Clone, Debug, PartialEq)]
+pub struct EcologicalEventOdds {
+    pub wildfire: f32,
+    pub flood: f32,
+    pub blight: f32,
+    pub invasive_species: f32,
+    pub nutrient_windfall: f32,
+}
+
+impl Default for EcologicalEventOdds {
+    fn default() -> Self {
+        Self {
+            wildfire: 0.02,
+            flood: 0.02,
+            blight: 0.03,
+            invasive_species: 0.02,
+            nutrient_windfall: 0.04,
+        }
+    }
+}
+
+impl EcologicalEventOdds {
+    /// Odds under which no event ever happens
+    pub fn none() -> Self {
+        Self {
+            wildfire: 0.0,
+            flood: 0.0,
+            blight: 0.0,
+            invasive_species: 0.0,
+            nutrient_windfall: 0.0,
+        }
+    }
+
+    /// Daily chance of a specific event
+    pub fn chance(&self, kind: EcologicalEventKind) -> f32 {
+        match kind {
+            EcologicalEventKind::Wildfire => self.wildfire,
+            EcologicalEventKind::Flood => self.flood,
+            EcologicalEventKind::Blight => self.blight,
+            EcologicalEventKind::InvasiveSpecies => self.invasive_species,
+            EcologicalEventKind::NutrientWindfall => self.nutrient_windfall,
+        }
+    }
+
+    /// Set the daily chance of a specific event
+    pub fn with_chance(mut self, kind: EcologicalEventKind, chance: f32) -> Self {
+        let slot = match kind {
+            EcologicalEventKind::Wildfire => &mut self.wildfire,
+            EcologicalEventKind::Flood => &mut self.flood,
+            EcologicalEventKind::Blight => &mut self.blight,
+            EcologicalEventKind::InvasiveSpecies => &mut self.invasive_species,
+            EcologicalEventKind::NutrientWindfall => &mut self.nutrient_windfall,
+        };
+        *slot = chance.clamp(0.0, 1.0);
+        self
+    }
+}
+
+/// Seeded random event generator for a garden; the odds may be changed between rounds
+/// Events are drawn per day from the seed, so a garden with the same seed and odds sees the same events
+#[derive(Clone, Debug)]
+pub struct EcologicalEvents {
+    pub seed: u64,
+    pub odds: EcologicalEventOdds,
+}
+
+impl Default for EcologicalEvents {
+    fn default() -> Self {
+        Self::new(0xD15A_57E5)
+    }
+}
+
+impl EcologicalEvents {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            odds: EcologicalEventOdds::default(),
+        }
+    }
+
+    pub fn with_odds(mut self, odds: EcologicalEventOdds) -> Self {
+        self.odds = odds;
+        self
+    }
+
+    /// Random number generator for a given day's event
+    fn rng_for(&self, day: u32) -> fastrand::Rng {
+        fastrand::Rng::with_seed(self.seed ^ (day as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F))
+    }
+
+    /// Which event (if any) strikes on a given day
+    pub fn event_on(&self, day: u32) -> Option<EcologicalEventKind> {
+        let mut roll = self.rng_for(day).f32();
+        for kind in EcologicalEventKind::all() {
+            let chance = self.odds.chance(kind);
+            if roll < chance {
+                return Some(kind);
+            }
+            roll -= chance;
+        }
+        None
+    }
+}
+
+/// What an event did to the garden
+struct EventOutcome {
+    description: String,
+    creatures_affected: u32,
+    matter_transformed: u32,
+}
+
+/// Ecological event phase - occasionally strikes the garden with a seeded random event
+/// Runs before the death phase so creatures killed by an event are already gone when the day's deaths are counted
+pub struct EcologicalEventPhase;
+
+impl EcologicalEventPhase {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn wildfire(&self, ecosystem: &mut EcosystemPopulation, events: &mut Vec<LifecycleEvent>) -> Result<Option<EventOutcome>, PhaseError> {
+        let mut burned_total = 0;
+        let mut plants_burned = 0;
+        let mut burned_out = Vec::new();
+        let mut burned_by_tile: Vec<(TilePosition, u32)> = Vec::new();
+
+        for creature in ecosystem.living_creatures_mut() {
+            if creature.species.kingdom != Kingdom::Plant {
+                continue;
+            }
+            let burned = creature.biomass.remove_matter(MatterType::PlantMatter, creature.biomass.plant_matter.div_ceil(2));
+            burned_total += burned;
+            plants_burned += 1;
+            burned_by_tile.push((creature.tile, burned));
+
+            if creature.biomass.is_empty() {
+                creature.die(DeathCause::Disaster);
+                burned_out.push(creature.id);
+            }
+        }
+
+        for cohort in &mut ecosystem.cohorts {
+            if cohort.member.species.kingdom != Kingdom::Plant {
+                continue;
+            }
+            let burned = cohort.member.biomass.remove_matter(MatterType::PlantMatter, cohort.member.biomass.plant_matter.div_ceil(2));
+            burned_total += burned * cohort.count;
+            plants_burned += cohort.count;
+            burned_by_tile.push((cohort.member.tile, burned * cohort.count));
+        }
+
+        if plants_burned == 0 {
+            return Ok(None);
+        }
+
+        for creature_id in burned_out {
+            events.push(LifecycleEvent::CreatureDied { creature_id, cause: DeathCause::Disaster });
+        }
+        ecosystem.remove_dead_creatures(self.phase_name())?;
+        self.remove_cohorts_where(ecosystem, events, |cohort| cohort.member.biomass.is_empty());
+
+        // Half of the burned biomass settles where it burned as ash, the rest as charred dead plant matter
+        let mut ash = 0;
+        for (tile, burned) in burned_by_tile {
+            let matter = ecosystem.grid.tile_mut(tile);
+            matter.add_matter(MatterType::SoilNutrients, burned / 2);
+            matter.add_matter(MatterType::DeadPlantMatter, burned - burned / 2);
+            ash += burned / 2;
+        }
+        let charred = burned_total - ash;
+        for (to_type, amount) in [(MatterType::SoilNutrients, ash), (MatterType::DeadPlantMatter, charred)] {
+            if amount > 0 {
+                events.push(LifecycleEvent::MatterTransformed { from_type: MatterType::PlantMatter, to_type, amount });
+            }
+        }
+
+        Ok(Some(EventOutcome {
+            description: format!("A wildfire burned {} plant matter across {} plants", burned_total, plants_burned),
+            creatures_affected: plants_burned,
+            matter_transformed: burned_total,
+        }))
+    }
+
+    fn flood(&self, ecosystem: &mut EcosystemPopulation) -> Option<EventOutcome> {
+        let mut washed_away = 0;
+        for matter in ecosystem.grid.tiles_mut() {
+            matter.add_matter(MatterType::GroundWater, FLOOD_WATER);
+
+            for matter_type in [MatterType::SoilNutrients, MatterType::DeadPlantMatter, MatterType::DeadAnimalMatter] {
+                let amount = matter.get_amount(matter_type) / 2;
+                washed_away += matter.consume_matter(matter_type, amount);
+            }
+        }
+
+        Some(EventOutcome {
+            description: format!("A flood soaked the garden and washed away {} nutrients and dead matter", washed_away),
+            creatures_affected: 0,
+            matter_transformed: 0,
+        })
+    }
+
+    fn blight(&self, ecosystem: &mut EcosystemPopulation, events: &mut Vec<LifecycleEvent>, rng: &mut fastrand::Rng) -> Result<Option<EventOutcome>, PhaseError> {
+        let mut hosts: Vec<&String> = ecosystem.living_population_by_species
+            .iter()
+            .filter(|(_, &count)| count > 0)
+            .map(|(name, _)| name)
+            .collect();
+        if hosts.is_empty() {
+            return Ok(None);
+        }
+        hosts.sort();
+        let target = hosts[rng.usize(..hosts.len())].clone();
+
+        // Half of the individuals, chosen at random, and half of each cohort
+        let mut victims: Vec<CreatureId> = ecosystem.creatures_by_species(&target).map(|c| c.id).collect();
+        rng.shuffle(&mut victims);
+        victims.truncate(victims.len().div_ceil(2));
+
+        let mut killed = victims.len() as u32;
+        for creature_id in victims {
+            if let Some(creature) = ecosystem.creatures.get_mut(creature_id) {
+                creature.die(DeathCause::Disaster);
+                events.push(LifecycleEvent::CreatureDied { creature_id, cause: DeathCause::Disaster });
+            }
+        }
+        let matter_transformed = ecosystem.remove_dead_creatures(self.phase_name())?
+            .iter()
+            .map(|(creature, _)| creature.biomass.total())
+            .sum::<u32>();
+
+        let mut cohort_matter = 0;
+        for index in 0..ecosystem.cohorts.len() {
+            let cohort = &mut ecosystem.cohorts[index];
+            if cohort.member.species.name != target {
+                continue;
+            }
+            let dead = cohort.count.div_ceil(2);
+            cohort.count -= dead;
+            let remains = cohort.member.get_death_matter();
+            let (cohort_id, species, tile) = (cohort.id(), cohort.member.species.name, cohort.member.tile);
+
+            for (dead_matter_type, amount_per_member) in remains {
+                ecosystem.grid.tile_mut(tile).add_matter(dead_matter_type, amount_per_member * dead);
+                cohort_matter += amount_per_member * dead;
+            }
+            ecosystem.record_deaths(species, &DeathCause::Disaster, dead);
+            killed += dead;
+            events.push(LifecycleEvent::CohortDied { cohort_id, species, cause: DeathCause::Disaster, count: dead });
+        }
+        ecosystem.cohorts.retain(|cohort| cohort.count > 0);
+
+        Ok(Some(EventOutcome {
+            description: format!("A blight struck the {}, killing {}", target, killed),
+            creatures_affected: killed,
+            matter_transformed: matter_transformed + cohort_matter,
+        }))
+    }
+
+    fn invasive_species(&self, ecosystem: &mut EcosystemPopulation, rng: &mut fastrand::Rng) -> Option<EventOutcome> {
+        let mut candidates: Vec<_> = get_all_species().into_values().collect();
+        candidates.sort_by_key(|species| species.name);
+
+        // Newcomers are preferably species the garden doesn't have yet
+        let absent: Vec<_> = candidates
+            .iter()
+            .filter(|species| ecosystem.population_count(species.name) == 0)
+            .cloned()
+            .collect();
+        if !absent.is_empty() {
+            candidates = absent;
+        }
+        if candidates.is_empty() {
+            return None;
+        }
+        let invader = candidates.swap_remove(rng.usize(..candidates.len()));
+
+        let starting_biomass = invader.starting_biomass();
+        let name = invader.name;
+        let invader = std::sync::Arc::new(invader);
+        let tile = ecosystem.grid.position_of(rng.usize(..ecosystem.grid.tile_count()));
+        for _ in 0..INVASIVE_ARRIVALS {
+            ecosystem.add_mature_creature_at(invader.clone(), starting_biomass, tile);
+        }
+
+        Some(EventOutcome {
+            description: format!("{} invasive {} arrived at tile ({}, {})", INVASIVE_ARRIVALS, name, tile.x, tile.y),
+            creatures_affected: INVASIVE_ARRIVALS,
+            matter_transformed: 0,
+        })
+    }
+
+    fn nutrient_windfall(&self, ecosystem: &mut EcosystemPopulation, rng: &mut fastrand::Rng) -> Option<EventOutcome> {
+        let tile = ecosystem.grid.position_of(rng.usize(..ecosystem.grid.tile_count()));
+        ecosystem.grid.tile_mut(tile).add_matter(MatterType::SoilNutrients, WINDFALL_NUTRIENTS);
+
+        Some(EventOutcome {
+            description: format!("A nutrient windfall enriched tile ({}, {}) with {} nutrients", tile.x, tile.y, WINDFALL_NUTRIENTS),
+            creatures_affected: 0,
+            matter_transformed: 0,
+        })
+    }
+
+    /// Remove whole cohorts killed by an event, recording their deaths
+    fn remove_cohorts_where(
+        &self,
+        ecosystem: &mut EcosystemPopulation,
+        events: &mut Vec<LifecycleEvent>,
+        condition: impl Fn(&super::Cohort) -> bool,
+    ) {
+        let (dead, surviving): (Vec<_>, Vec<_>) = std::mem::take(&mut ecosystem.cohorts).into_iter().partition(|cohort| condition(cohort));
+        ecosystem.cohorts = surviving;
+
+        for cohort in dead {
+            for (dead_matter_type, amount_per_member) in cohort.member.get_death_matter() {
+                ecosystem.grid.tile_mut(cohort.member.tile).add_matter(dead_matter_type, amount_per_member * cohort.count);
+            }
+            ecosystem.record_deaths(cohort.member.species.name, &DeathCause::Disaster, cohort.count);
+            events.push(LifecycleEvent::CohortDied {
+                cohort_id: cohort.id(),
+                species: cohort.member.species.name,
+                cause: DeathCause::Disaster,
+                count: cohort.count,
+            });
+        }
+    }
+}
+
+impl LifecyclePhase for EcologicalEventPhase {
+    fn execute(&self, ecosystem: &mut EcosystemPopulation) -> Result<PhaseResult, PhaseError> {
+        let day = ecosystem.current_day;
+        let Some(kind) = ecosystem.ecological_events.event_on(day) else {
+            return Ok(PhaseResult {
+                creatures_processed: 0,
+                matter_transformed: 0,
+                events: Vec::new(),
+            });
+        };
+
+        // Continue the day's random stream past the roll that picked the event to choose its target
+        let mut rng = ecosystem.ecological_events.rng_for(day);
+        rng.f32();
+
+        let mut effects = Vec::new();
+        let outcome = match kind {
+            EcologicalEventKind::Wildfire => self.wildfire(ecosystem, &mut effects)?,
+            EcologicalEventKind::Flood => self.flood(ecosystem),
+            EcologicalEventKind::Blight => self.blight(ecosystem, &mut effects, &mut rng)?,
+            EcologicalEventKind::InvasiveSpecies => self.invasive_species(ecosystem, &mut rng),
+            EcologicalEventKind::NutrientWindfall => self.nutrient_windfall(ecosystem, &mut rng),
+        };
+
+        // Events with nothing to act on (e.g. a blight in an empty garden) pass unnoticed
+        let Some(outcome) = outcome else {
+            return Ok(PhaseResult {
+                creatures_processed: 0,
+                matter_transformed: 0,
+                events: Vec::new(),
+            });
+        };
+
+        let mut events = vec![LifecycleEvent::EcologicalEvent { kind, description: outcome.description }];
+        events.extend(effects);
+
+        Ok(PhaseResult {
+            creatures_processed: outcome.creatures_affected,
+            matter_transformed: outcome.matter_transformed,
+            events,
+        })
+    }
+
+    fn validate_preconditions(&self, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+        invariants::check_population_invariants(self.phase_name(), ecosystem)
+    }
+
+    fn validate_postconditions(&self, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+        // Anything an event killed must already be removed
+        invariants::check_no_dead_creatures(self.phase_name(), ecosystem)?;
+        invariants::check_population_invariants(self.phase_name(), ecosystem)
+    }
+
+    fn phase_name(&self) -> &'static str {
+        "Ecological Events"
+    }
+}
+
+impl Default for EcologicalEventPhase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
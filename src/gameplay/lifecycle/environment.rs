@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use super::{
+    LifecyclePhase, PhaseResult, PhaseError,
+    EcosystemPopulation, EcosystemMatter, GardenGrid, MatterType, IndividualCreature, Season
+};
+use super::invariants;
+
+/// Environment phase - compares each creature's tolerance ranges against the matter pools of its tile
+/// Creatures outside their growth ranges accumulate stress; outside reproduction ranges they can't breed
+/// Species also go dormant or stop breeding according to the season, and resting life stages or stressed species
+/// go dormant according to their life stages
+pub struct EnvironmentPhase;
+
+impl EnvironmentPhase {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check that every matter pool of a tile, at garden scale, falls within its (min, max) range
+    pub(crate) fn within_ranges(ranges: &HashMap<MatterType, (i32, i32)>, matter: &EcosystemMatter, grid: &GardenGrid) -> bool {
+        ranges.iter().all(|(matter_type, (min, max))| {
+            let amount = grid.garden_scale(*matter_type, matter.get_amount(*matter_type)) as i64;
+            amount >= *min as i64 && amount <= *max as i64
+        })
+    }
+
+    /// Update a creature's environment flags and stress counter
+    fn apply_environment(creature: &mut IndividualCreature, matter: &EcosystemMatter, grid: &GardenGrid, season: Season) {
+        let seasonal = &creature.species.seasonal_behavior;
+        creature.dormant = seasonal.is_dormant_in(season);
+
+        // Dormant creatures wait the season out untouched by the environment
+        if creature.dormant {
+            creature.in_growth_environment = false;
+            creature.in_reproduction_environment = false;
+            return;
+        }
+
+        let growth_ranges = &creature.species.growth_requirements.environmental_factors;
+        let reproduction_ranges = &creature.species.reproduction_requirements.environmental_requirements;
+
+        creature.in_growth_environment = Self::within_ranges(growth_ranges, matter, grid);
+        creature.in_reproduction_environment = seasonal.can_breed_in(season)
+            && Self::within_ranges(reproduction_ranges, matter, grid);
+
+        // Stages that don't feed (seeds, eggs) rest through any conditions until they move on
+        if creature.stage_traits().feeding_multiplier <= 0.0 {
+            creature.dormant = true;
+            creature.in_reproduction_environment = false;
+            return;
+        }
+
+        // Stress builds while conditions are outside the species' tolerance and resets once they recover;
+        // species that can go dormant once it has built up for long enough, and wake when conditions recover
+        if creature.in_growth_environment {
+            creature.days_in_bad_environment = 0;
+        } else if creature.species.life_stages.stress_dormancy_days.is_some_and(|days| creature.days_in_bad_environment >= days) {
+            creature.dormant = true;
+            creature.in_reproduction_environment = false;
+        } else {
+            creature.days_in_bad_environment += 1;
+        }
+    }
+}
+
+impl LifecyclePhase for EnvironmentPhase {
+    fn execute(&self, ecosystem: &mut EcosystemPopulation) -> Result<PhaseResult, PhaseError> {
+        let mut creatures_processed = 0;
+        let grid = ecosystem.grid.clone();
+        let season = ecosystem.season();
+
+        for creature in ecosystem.living_creatures_mut() {
+            creatures_processed += 1;
+            Self::apply_environment(creature, grid.tile(creature.tile), &grid, season);
+        }
+
+        for cohort in &mut ecosystem.cohorts {
+            creatures_processed += cohort.count;
+            let tile = cohort.member.tile;
+            Self::apply_environment(&mut cohort.member, grid.tile(tile), &grid, season);
+        }
+
+        Ok(PhaseResult {
+            creatures_processed,
+            matter_transformed: 0, // Environment checks only observe matter
+            events: Vec::new(),
+        })
+    }
+
+    fn validate_preconditions(&self, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+        invariants::check_population_invariants(self.phase_name(), ecosystem)
+    }
+
+    fn validate_postconditions(&self, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+        invariants::check_population_invariants(self.phase_name(), ecosystem)
+    }
+
+    fn phase_name(&self) -> &'static str {
+        "Environment"
+    }
+}
+
+impl Default for EnvironmentPhase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
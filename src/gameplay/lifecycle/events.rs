@@ -0,0 +1,191 @@
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use std::collections::HashMap;
+use super::{CreatureId, DeathCause, MatterType, DailyResult, DailySummary, LifecycleEvent, EcologicalEventKind};
+
+/// A creature was born to a parent already in the garden
+/// In cohort mode the ids are the parent and offspring cohorts', and `count` covers the whole litter
+#[derive(Event, Clone, Debug)]
+pub struct CreatureBornEvent {
+    pub day: u32,
+    pub creature_id: CreatureId,
+    pub parent_id: CreatureId,
+    pub species: &'static str,
+    pub count: u32,
+}
+
+/// A creature died and its biomass returned to the garden
+/// In cohort mode the id is the cohort's, and `count` covers every member that died
+#[derive(Event, Clone, Debug)]
+pub struct CreatureDiedEvent {
+    pub day: u32,
+    pub creature_id: CreatureId,
+    pub species: &'static str,
+    pub cause: DeathCause,
+    pub count: u32,
+}
+
+/// A juvenile matured into an adult
+/// In cohort mode the id is the cohort's, and `count` covers every member that matured
+#[derive(Event, Clone, Debug)]
+pub struct CreatureMaturedEvent {
+    pub day: u32,
+    pub creature_id: CreatureId,
+    pub species: &'static str,
+    pub count: u32,
+}
+
+/// A creature finished feeding for the day
+/// In cohort mode the id is the cohort's, `count` covers every member, and they all share the one `satisfaction`
+#[derive(Event, Clone, Debug)]
+pub struct CreatureFedEvent {
+    pub day: u32,
+    pub creature_id: CreatureId,
+    pub species: &'static str,
+    pub satisfaction: f32,
+    pub count: u32,
+}
+
+/// Matter changed form during the day (e.g. sunlight into plant biomass)
+#[derive(Event, Clone, Debug)]
+pub struct MatterTransformedEvent {
+    pub day: u32,
+    pub from_type: MatterType,
+    pub to_type: MatterType,
+    pub amount: u32,
+}
+
+/// A random ecological event struck the garden at the start of the day
+#[derive(Event, Clone, Debug)]
+pub struct EcologicalEventOccurred {
+    pub day: u32,
+    pub kind: EcologicalEventKind,
+    pub description: String,
+}
+
+/// Sent once per simulated day, after all of that day's lifecycle events
+#[derive(Event, Clone, Debug)]
+pub struct DayCompletedEvent {
+    pub summary: DailySummary,
+}
+
+/// Writers for every lifecycle event published after a day is simulated
+#[derive(SystemParam)]
+pub struct LifecycleEventWriters<'w> {
+    born: EventWriter<'w, CreatureBornEvent>,
+    died: EventWriter<'w, CreatureDiedEvent>,
+    matured: EventWriter<'w, CreatureMaturedEvent>,
+    fed: EventWriter<'w, CreatureFedEvent>,
+    matter_transformed: EventWriter<'w, MatterTransformedEvent>,
+    ecological: EventWriter<'w, EcologicalEventOccurred>,
+    day_completed: EventWriter<'w, DayCompletedEvent>,
+}
+
+impl LifecycleEventWriters<'_> {
+    /// Publish a day's lifecycle events in the order they happened, followed by the day summary
+    pub fn publish(&mut self, result: &DailyResult, species_by_id: &HashMap<CreatureId, &'static str>) {
+        let species_of = |id: &CreatureId| species_by_id.get(id).copied().unwrap_or("Unknown");
+        let day = result.day;
+
+        for event in &result.total_events {
+            match event {
+                LifecycleEvent::CreatureReproduced { parent_id, offspring_id } => {
+                    self.born.write(CreatureBornEvent {
+                        day,
+                        creature_id: *offspring_id,
+                        parent_id: *parent_id,
+                        species: species_of(parent_id),
+                        count: 1,
+                    });
+                }
+                LifecycleEvent::CreatureDied { creature_id, cause } => {
+                    self.died.write(CreatureDiedEvent {
+                        day,
+                        creature_id: *creature_id,
+                        species: species_of(creature_id),
+                        cause: cause.clone(),
+                        count: 1,
+                    });
+                }
+                // Cohort events carry their cohort's species, so they don't depend on the ids seen before the day
+                LifecycleEvent::CohortReproduced { parent_cohort_id, offspring_cohort_id, species, offspring } => {
+                    self.born.write(CreatureBornEvent {
+                        day,
+                        creature_id: *offspring_cohort_id,
+                        parent_id: *parent_cohort_id,
+                        species,
+                        count: *offspring,
+                    });
+                }
+                LifecycleEvent::CohortDied { cohort_id, species, cause, count } => {
+                    self.died.write(CreatureDiedEvent {
+                        day,
+                        creature_id: *cohort_id,
+                        species,
+                        cause: cause.clone(),
+                        count: *count,
+                    });
+                }
+                LifecycleEvent::CreatureGrew { creature_id } => {
+                    self.matured.write(CreatureMaturedEvent {
+                        day,
+                        creature_id: *creature_id,
+                        species: species_of(creature_id),
+                        count: 1,
+                    });
+                }
+                LifecycleEvent::CreatureFed { creature_id, satisfaction } => {
+                    self.fed.write(CreatureFedEvent {
+                        day,
+                        creature_id: *creature_id,
+                        species: species_of(creature_id),
+                        satisfaction: *satisfaction,
+                        count: 1,
+                    });
+                }
+                LifecycleEvent::CohortGrew { cohort_id, species, count } => {
+                    self.matured.write(CreatureMaturedEvent {
+                        day,
+                        creature_id: *cohort_id,
+                        species,
+                        count: *count,
+                    });
+                }
+                LifecycleEvent::CohortFed { cohort_id, species, count, satisfaction } => {
+                    self.fed.write(CreatureFedEvent {
+                        day,
+                        creature_id: *cohort_id,
+                        species,
+                        satisfaction: *satisfaction,
+                        count: *count,
+                    });
+                }
+                LifecycleEvent::MatterTransformed { from_type, to_type, amount } => {
+                    self.matter_transformed.write(MatterTransformedEvent {
+                        day,
+                        from_type: *from_type,
+                        to_type: *to_type,
+                        amount: *amount,
+                    });
+                }
+                LifecycleEvent::EcologicalEvent { kind, description } => {
+                    self.ecological.write(EcologicalEventOccurred {
+                        day,
+                        kind: *kind,
+                        description: description.clone(),
+                    });
+                }
+                LifecycleEvent::VariantEmerged { .. }
+                | LifecycleEvent::Parasitized { .. }
+                | LifecycleEvent::DiseaseOutbreak { .. }
+                | LifecycleEvent::Infected { .. }
+                | LifecycleEvent::Recovered { .. }
+                | LifecycleEvent::StageReached { .. } => {}
+            }
+        }
+
+        self.day_completed.write(DayCompletedEvent {
+            summary: result.get_summary(),
+        });
+    }
+}
@@ -1,42 +1,104 @@
-use bevy::prelude::*;
 use super::{
     LifecyclePhase, PhaseResult, PhaseError, LifecycleEvent,
-    EcosystemPopulation, MatterType, FeedingResult, CreatureId
+    EcosystemPopulation, EcosystemMatter, MatterType, FeedingResult, CreatureId, CreatureBiomass,
+    GardenGrid, TilePosition, SymbiosisMap, DeathCause, trophic_level, scale_trait
 };
-use crate::gameplay::species::BiomassConversion;
-use std::collections::HashMap;
+use super::invariants::{self, INVARIANT_CHECKS_ENABLED};
+use super::feeding_policy::{FeedingAllocationPolicy, FeedingRequest, AllocationContext, allocate_by_priority};
+use crate::gameplay::species::{BiomassConversion, Kingdom, Species};
+use std::collections::{BTreeMap, HashMap};
 
 /// Represents a creature's feeding demand
 #[derive(Debug)]
 struct FeedingDemand {
     creature_id: CreatureId,
-    matter_demands: HashMap<MatterType, u32>,
+    tile: TilePosition,
+    matter_demands: Vec<(MatterType, u32)>,
+    introduction_day: u32,
     introduction_order: u32,
+    is_mature: bool,
+    priority: i32,
+    /// Which of the species' diets this demand is for (0 = the preferred one)
+    diet: usize,
+    /// Fraction of the creature's whole need this demand stands for
+    share: f32,
+    /// Whether the species has a less preferred diet to fall back on
+    has_fallback: bool,
+    species: &'static str,
 }
 
 /// Result of resource allocation
 #[derive(Debug)]
 struct FeedingAllocation {
     creature_id: CreatureId,
-    allocated_resources: HashMap<MatterType, u32>,
+    /// Resources granted from each diet eaten, as (diet, resources)
+    allocated_resources: Vec<(usize, Vec<(MatterType, u32)>)>,
     satisfaction_level: f32,
+    species: &'static str,
+}
+
+/// Most tiles a creature can reach beyond its own
+const MAX_NEIGHBOURS: usize = 4;
+
+/// Resources granted to each demand so far, and what each still lacks, as allocation moves between tiles
+struct AllocationProgress {
+    granted: Vec<Vec<(MatterType, u32)>>,
+    unmet: Vec<Vec<(MatterType, u32)>>,
+}
+
+/// Requests competing for one tile's pool of one matter type, with the (demand, matter) index each came from
+type PoolRequests = (Vec<FeedingRequest>, Vec<(usize, usize)>);
+
+/// Matter converted while feeding, totalled per (from, to) pair in the order each pair first appears
+/// Like decay, feeding reports its conversions for the whole garden rather than creature by creature
+#[derive(Default)]
+struct MatterFlows(Vec<(MatterType, MatterType, u32)>);
+
+impl MatterFlows {
+    fn add(&mut self, from_type: MatterType, to_type: MatterType, amount: u32) {
+        if amount == 0 {
+            return;
+        }
+        match self.0.iter_mut().find(|(from, to, _)| *from == from_type && *to == to_type) {
+            Some((_, _, total)) => *total += amount,
+            None => self.0.push((from_type, to_type, amount)),
+        }
+    }
+
+    fn total(&self) -> u32 {
+        self.0.iter().map(|(_, _, amount)| amount).sum()
+    }
+
+    fn into_events(self) -> impl Iterator<Item = LifecycleEvent> {
+        self.0.into_iter().map(|(from_type, to_type, amount)| LifecycleEvent::MatterTransformed { from_type, to_type, amount })
+    }
 }
 
 /// Feeding phase - creatures compete for resources and convert matter
 /// Processes creatures in trophic order: Decomposers → Producers → Consumers
-pub struct FeedingPhaseImpl;
+pub struct FeedingPhaseImpl {
+    /// Overrides the ecosystem's selected feeding policy when set
+    policy: Option<Box<dyn FeedingAllocationPolicy>>,
+}
 
 impl FeedingPhaseImpl {
     pub fn new() -> Self {
-        Self
+        Self { policy: None }
+    }
+
+    /// Create a feeding phase that always uses the given allocation policy
+    pub fn with_policy(policy: Box<dyn FeedingAllocationPolicy>) -> Self {
+        Self { policy: Some(policy) }
     }
 
-    /// Calculate feeding demand for a creature
-    fn calculate_feeding_demand(&self, creature: &crate::gameplay::lifecycle::IndividualCreature) -> FeedingDemand {
-        let mut matter_demands = HashMap::new();
+    /// Calculate feeding demand for a creature, or for `count` identical cohort members,
+    /// covering `share` of its need from one of its diets; None if the species has no such diet
+    fn calculate_feeding_demand(&self, creature: &crate::gameplay::lifecycle::IndividualCreature, count: u32, diet: usize, share: f32) -> Option<FeedingDemand> {
+        let (requirements, _) = creature.species.feeding_requirements.diet(diet)?;
+        let mut matter_demands = Vec::with_capacity(requirements.len());
         
-        // Get base requirements from species
-        for (matter_type, base_amount) in &creature.species.feeding_requirements.base_requirements {
+        // Get this diet's requirements from species
+        for (matter_type, base_amount) in requirements {
             let mut required_amount = *base_amount;
             
             // Apply maturity multiplier
@@ -44,123 +106,250 @@ impl FeedingPhaseImpl {
                 required_amount = (required_amount as f32 * creature.species.feeding_requirements.maturity_multiplier) as u32;
             }
             
-            matter_demands.insert(*matter_type, required_amount);
+            // Some life stages eat more or less than others
+            required_amount = (required_amount as f32 * creature.stage_traits().feeding_multiplier) as u32;
+
+            // Larger creatures eat more
+            required_amount = scale_trait(required_amount, creature.genome.size);
+            
+            // A fallback diet only has to make up what the preferred ones left unmet
+            let total = (required_amount * count) as f32 * share;
+            matter_demands.push((*matter_type, total.ceil() as u32));
         }
 
-        FeedingDemand {
+        Some(FeedingDemand {
             creature_id: creature.id,
+            tile: creature.tile,
             matter_demands,
+            introduction_day: creature.introduction_day,
             introduction_order: creature.introduction_order,
-        }
+            is_mature: creature.is_mature(),
+            priority: creature.species.feeding_requirements.priority,
+            diet,
+            share,
+            has_fallback: diet < creature.species.feeding_requirements.alternative_diets.len(),
+            species: creature.species.name,
+        })
     }
 
     /// Allocate resources among competing creatures
+    /// Creatures first compete for their own tile's pools, then make up any shortfall from neighbouring tiles
+    /// one neighbour at a time, so nobody is granted more than they asked for
     fn allocate_resources(
         &self,
-        demands: Vec<FeedingDemand>,
-        ecosystem_matter: &mut crate::gameplay::lifecycle::EcosystemMatter,
+        mut demands: Vec<FeedingDemand>,
+        policy: &dyn FeedingAllocationPolicy,
+        day: u32,
+        grid: &mut GardenGrid,
     ) -> Vec<FeedingAllocation> {
-        let mut allocations = Vec::new();
-
-        // Group demands by matter type to handle competition
-        let mut matter_demands: HashMap<MatterType, Vec<(CreatureId, u32, u32)>> = HashMap::new();
-        
-        for demand in &demands {
-            for (matter_type, amount) in &demand.matter_demands {
-                matter_demands.entry(*matter_type)
-                    .or_insert_with(Vec::new)
-                    .push((demand.creature_id, *amount, demand.introduction_order));
+        // Each demand's requests are moved into what it still lacks, leaving only their total behind
+        let requested: Vec<u32> = demands
+            .iter()
+            .map(|demand| demand.matter_demands.iter().map(|(_, amount)| amount).sum())
+            .collect();
+        let mut progress = AllocationProgress {
+            granted: vec![Vec::new(); demands.len()],
+            unmet: demands.iter_mut().map(|demand| std::mem::take(&mut demand.matter_demands)).collect(),
+        };
+        // Round 0 is the creature's own tile; round n draws on its nth neighbour, for creatures still hungry
+        for round in 0..=MAX_NEIGHBOURS {
+            let tiles: Vec<Option<TilePosition>> = demands
+                .iter()
+                .zip(&progress.unmet)
+                .map(|(demand, unmet)| match round {
+                    0 => Some(demand.tile),
+                    _ if unmet.iter().all(|(_, amount)| *amount == 0) => None,
+                    _ => grid.neighbours(demand.tile).get(round - 1).copied(),
+                })
+                .collect();
+
+            self.allocate_round(&demands, &tiles, policy, day, grid, &mut progress);
+
+            if progress.unmet.iter().all(|matter| matter.iter().all(|(_, amount)| *amount == 0)) {
+                break;
             }
         }
 
-        // Process each matter type allocation
-        let mut creature_allocations: HashMap<CreatureId, HashMap<MatterType, u32>> = HashMap::new();
-
-        for (matter_type, requesters) in matter_demands {
-            let available_amount = ecosystem_matter.get_amount(matter_type);
-            let total_demand: u32 = requesters.iter().map(|(_, amount, _)| amount).sum();
+        // Convert to allocations with satisfaction levels
+        demands
+            .into_iter()
+            .zip(progress.granted)
+            .zip(requested)
+            .map(|((demand, allocated), total_requested)| {
+                let total_allocated: u32 = allocated.iter().map(|(_, amount)| amount).sum();
+                
+                let satisfaction_level = if total_requested > 0 {
+                    total_allocated as f32 / total_requested as f32
+                } else {
+                    1.0
+                };
 
-            if total_demand <= available_amount {
-                // Enough for everyone
-                for (creature_id, amount, _) in requesters {
-                    creature_allocations.entry(creature_id)
-                        .or_insert_with(HashMap::new)
-                        .insert(matter_type, amount);
-                    ecosystem_matter.consume_matter(matter_type, amount);
+                FeedingAllocation {
+                    creature_id: demand.creature_id,
+                    allocated_resources: vec![(demand.diet, allocated)],
+                    satisfaction_level: satisfaction_level.min(1.0) * demand.share,
+                    species: demand.species,
                 }
-            } else {
-                // Not enough - allocate proportionally based on introduction order
-                let mut sorted_requesters = requesters;
-                sorted_requesters.sort_by_key(|(_, _, order)| *order);
-
-                let mut remaining = available_amount;
-                for (creature_id, requested, _) in sorted_requesters {
-                    let allocated = requested.min(remaining);
-                    if allocated > 0 {
-                        creature_allocations.entry(creature_id)
-                            .or_insert_with(HashMap::new)
-                            .insert(matter_type, allocated);
-                        ecosystem_matter.consume_matter(matter_type, allocated);
-                        remaining -= allocated;
-                    }
+            })
+            .collect()
+    }
+
+    /// Share out one tile's pools per matter type among the creatures drawing on that tile this round
+    fn allocate_round(
+        &self,
+        demands: &[FeedingDemand],
+        tiles: &[Option<TilePosition>],
+        policy: &dyn FeedingAllocationPolicy,
+        day: u32,
+        grid: &mut GardenGrid,
+        progress: &mut AllocationProgress,
+    ) {
+        // Group unmet demands by tile and matter type to handle competition, remembering which demand each request came from
+        let mut pool_demands: HashMap<(TilePosition, MatterType), PoolRequests> = HashMap::new();
+
+        for (demand_index, demand) in demands.iter().enumerate() {
+            let Some(tile) = tiles[demand_index] else {
+                continue;
+            };
+            for (matter_index, (matter_type, amount)) in progress.unmet[demand_index].iter().enumerate() {
+                if *amount == 0 {
+                    continue;
                 }
+                let (requests, request_indices) = pool_demands.entry((tile, *matter_type)).or_default();
+                requests.push(FeedingRequest {
+                    creature_id: demand.creature_id,
+                    amount: *amount,
+                    introduction_day: demand.introduction_day,
+                    introduction_order: demand.introduction_order,
+                    is_mature: demand.is_mature,
+                    priority: demand.priority,
+                });
+                request_indices.push((demand_index, matter_index));
             }
         }
 
-        // Convert to allocations with satisfaction levels
-        for demand in demands {
-            let empty_map = HashMap::new();
-            let allocated = creature_allocations.get(&demand.creature_id)
-                .unwrap_or(&empty_map);
-            
-            let total_requested: u32 = demand.matter_demands.values().sum();
-            let total_allocated: u32 = allocated.values().sum();
-            
-            let satisfaction_level = if total_requested > 0 {
-                total_allocated as f32 / total_requested as f32
-            } else {
-                1.0
-            };
-
-            allocations.push(FeedingAllocation {
-                creature_id: demand.creature_id,
-                allocated_resources: allocated.clone(),
-                satisfaction_level: satisfaction_level.min(1.0),
-            });
+        // Each tile's pools are independent, so the order they are processed in doesn't matter
+        for ((tile, matter_type), (requesters, request_indices)) in pool_demands {
+            let matter = grid.tile_mut(tile);
+            let available_amount = matter.get_amount(matter_type);
+            let context = AllocationContext { day, matter_type };
+            let granted = allocate_by_priority(policy, available_amount, &requesters, &context);
+
+            let mut total_granted = 0;
+            for ((demand_index, matter_index), allocated) in request_indices.into_iter().zip(granted) {
+                if allocated > 0 {
+                    progress.granted[demand_index].push((matter_type, allocated));
+                    progress.unmet[demand_index][matter_index].1 -= allocated;
+                    total_granted += allocated;
+                }
+            }
+            matter.consume_matter(matter_type, total_granted);
         }
-
-        allocations
     }
 
     /// Apply feeding results to creatures
     fn apply_feeding_results(
         &self,
         allocations: Vec<FeedingAllocation>,
+        symbiosis: &SymbiosisMap,
         ecosystem: &mut EcosystemPopulation,
+        flows: &mut MatterFlows,
     ) -> Result<Vec<LifecycleEvent>, PhaseError> {
-        let mut events = Vec::new();
+        let mut events = Vec::with_capacity(allocations.len());
+
+        // Crowding near carrying capacity reduces how well creatures feed
+        let mut feeding_multipliers: HashMap<&'static str, f32> = HashMap::new();
+        for allocation in &allocations {
+            if feeding_multipliers.contains_key(allocation.species) {
+                continue;
+            }
+            let Some(species) = ecosystem.creatures.get(allocation.creature_id)
+                .map(|creature| &creature.species)
+                .or_else(|| ecosystem.cohort(allocation.creature_id).map(|cohort| &cohort.member.species))
+            else {
+                continue;
+            };
+            let pressure = ecosystem.density_pressure(species);
+            feeding_multipliers.insert(species.name, ecosystem.carrying_capacity.feeding_multiplier(pressure));
+        }
 
+        let current_day = ecosystem.current_day;
+        // What each creature didn't keep of its meals, reused between creatures
+        let mut unused = Vec::new();
         for allocation in allocations {
-            if let Some(creature) = ecosystem.creatures.iter_mut().find(|c| c.id == allocation.creature_id) {
+            if let Some(creature) = ecosystem.creatures.get_mut(allocation.creature_id) {
+                let satisfaction_level = allocation.satisfaction_level
+                    * feeding_multipliers.get(allocation.species).copied().unwrap_or(1.0);
+
                 // Determine feeding result based on satisfaction
-                let feeding_result = if allocation.satisfaction_level >= 1.0 {
+                let feeding_result = if satisfaction_level >= 1.0 {
                     FeedingResult::FullyFed
-                } else if allocation.satisfaction_level >= creature.species.feeding_requirements.minimum_threshold {
-                    FeedingResult::PartiallyFed(allocation.satisfaction_level)
+                } else if satisfaction_level >= creature.species.feeding_requirements.minimum_threshold {
+                    FeedingResult::PartiallyFed(satisfaction_level)
                 } else {
                     FeedingResult::Starving
                 };
 
                 creature.set_fed_status(feeding_result.clone());
+                creature.fed_on_day = Some(current_day);
 
                 // Convert consumed matter to creature biomass based on species conversion
                 if allocation.satisfaction_level > 0.0 {
-                    self.apply_biomass_conversion(creature, &allocation.allocated_resources, &mut events, &mut ecosystem.ecosystem_matter)?;
+                    let multiplier = (1.0 + symbiosis.feeding_bonus(creature.species.name, creature.tile))
+                        * ecosystem.disease.feeding_multiplier(&creature.health)
+                        * creature.stage_traits().feeding_efficiency;
+                    let matter = ecosystem.grid.tile_mut(creature.tile);
+                    unused.clear();
+                    for (diet, resources) in &allocation.allocated_resources {
+                        Self::apply_biomass_conversion(creature, *diet, resources, multiplier, flows, matter, &mut unused)?;
+                    }
+                    Self::excrete(&creature.species.feeding_requirements.excretion, 1, &mut unused, flows, matter);
                 }
 
                 events.push(LifecycleEvent::CreatureFed {
                     creature_id: allocation.creature_id,
-                    satisfaction: allocation.satisfaction_level,
+                    satisfaction: satisfaction_level,
+                });
+            } else if let Some(cohort) = ecosystem.cohorts.iter_mut().find(|cohort| cohort.id() == allocation.creature_id) {
+                let satisfaction_level = allocation.satisfaction_level
+                    * feeding_multipliers.get(allocation.species).copied().unwrap_or(1.0);
+
+                let feeding_result = if satisfaction_level >= 1.0 {
+                    FeedingResult::FullyFed
+                } else if satisfaction_level >= cohort.member.species.feeding_requirements.minimum_threshold {
+                    FeedingResult::PartiallyFed(satisfaction_level)
+                } else {
+                    FeedingResult::Starving
+                };
+
+                cohort.member.set_fed_status(feeding_result);
+                cohort.member.fed_on_day = Some(current_day);
+
+                // Convert the whole cohort's meal at once, then share the gains between members
+                if allocation.satisfaction_level > 0.0 {
+                    let mut gains = cohort.member.clone();
+                    gains.biomass = CreatureBiomass::new(0, 0);
+                    let multiplier = (1.0 + symbiosis.feeding_bonus(cohort.member.species.name, cohort.member.tile))
+                        * ecosystem.disease.feeding_multiplier(&cohort.member.health)
+                        * cohort.member.stage_traits().feeding_efficiency;
+                    let matter = ecosystem.grid.tile_mut(cohort.member.tile);
+                    unused.clear();
+                    for (diet, resources) in &allocation.allocated_resources {
+                        Self::apply_biomass_conversion(&mut gains, *diet, resources, multiplier, flows, matter, &mut unused)?;
+                    }
+                    Self::excrete(&cohort.member.species.feeding_requirements.excretion, cohort.count, &mut unused, flows, matter);
+
+                    cohort.member.biomass.add_matter(MatterType::PlantMatter, gains.biomass.plant_matter / cohort.count);
+                    cohort.member.biomass.add_matter(MatterType::AnimalMatter, gains.biomass.animal_matter / cohort.count);
+                    matter.add_matter(MatterType::DeadPlantMatter, gains.biomass.plant_matter % cohort.count);
+                    matter.add_matter(MatterType::DeadAnimalMatter, gains.biomass.animal_matter % cohort.count);
+                }
+
+                events.push(LifecycleEvent::CohortFed {
+                    cohort_id: allocation.creature_id,
+                    species: cohort.member.species.name,
+                    count: cohort.count,
+                    satisfaction: satisfaction_level,
                 });
             }
         }
@@ -168,106 +357,260 @@ impl FeedingPhaseImpl {
         Ok(events)
     }
 
-    /// Convert consumed matter to creature biomass
+    /// Convert matter consumed from one of the creature's diets to biomass, using that diet's conversion
+    /// Adds what the creature didn't keep of each consumed matter type to `unused`, which it may excrete
     fn apply_biomass_conversion(
-        &self,
         creature: &mut crate::gameplay::lifecycle::IndividualCreature,
-        consumed_resources: &HashMap<MatterType, u32>,
-        events: &mut Vec<LifecycleEvent>,
-        ecosystem_matter: &mut crate::gameplay::lifecycle::EcosystemMatter,
+        diet: usize,
+        consumed_resources: &[(MatterType, u32)],
+        efficiency_multiplier: f32,
+        flows: &mut MatterFlows,
+        ecosystem_matter: &mut EcosystemMatter,
+        unused: &mut Vec<(MatterType, u32)>,
     ) -> Result<(), PhaseError> {
+        // Efficient feeders (and those helped by nearby partners) keep more of each meal, but never more than all of it;
+        // sick and senescent creatures keep less
+        let efficiency_trait = creature.genome.feeding_efficiency * efficiency_multiplier;
+        let Some((_, conversion)) = creature.species.feeding_requirements.diet(diet) else {
+            return Err(PhaseError::SystemError(format!("{} has no diet {}", creature.species.name, diet)));
+        };
+        let conversion = conversion.clone();
         for (matter_type, amount) in consumed_resources {
-            match &creature.species.feeding_requirements.biomass_conversion {
+            let kept = match &conversion {
                 BiomassConversion::PlantGrowth { efficiency } => {
-                    let gained_biomass = (*amount as f32 * efficiency) as u32;
-                    creature.biomass.add_matter(MatterType::PlantMatter, gained_biomass);
-                    
-                    if gained_biomass > 0 {
-                        events.push(LifecycleEvent::MatterTransformed {
-                            from_type: *matter_type,
-                            to_type: MatterType::PlantMatter,
-                            amount: gained_biomass,
-                        });
-                    }
+                    let gained_biomass = (*amount as f32 * (efficiency * efficiency_trait).min(1.0)) as u32;
+                    Self::gain_biomass(creature, *matter_type, MatterType::PlantMatter, gained_biomass, flows);
+                    gained_biomass
                 },
                 BiomassConversion::PlantToAnimal { efficiency } => {
-                    let gained_biomass = (*amount as f32 * efficiency) as u32;
-                    creature.biomass.add_matter(MatterType::AnimalMatter, gained_biomass);
-                    
-                    if gained_biomass > 0 {
-                        events.push(LifecycleEvent::MatterTransformed {
-                            from_type: *matter_type,
-                            to_type: MatterType::AnimalMatter,
-                            amount: gained_biomass,
-                        });
-                    }
+                    let gained_biomass = (*amount as f32 * (efficiency * efficiency_trait).min(1.0)) as u32;
+                    Self::gain_biomass(creature, *matter_type, MatterType::AnimalMatter, gained_biomass, flows);
+                    gained_biomass
                 },
                 BiomassConversion::AnimalToAnimal { efficiency } => {
-                    let gained_biomass = (*amount as f32 * efficiency) as u32;
-                    creature.biomass.add_matter(MatterType::AnimalMatter, gained_biomass);
-                    
-                    if gained_biomass > 0 {
-                        events.push(LifecycleEvent::MatterTransformed {
-                            from_type: *matter_type,
-                            to_type: MatterType::AnimalMatter,
-                            amount: gained_biomass,
-                        });
-                    }
+                    let gained_biomass = (*amount as f32 * (efficiency * efficiency_trait).min(1.0)) as u32;
+                    Self::gain_biomass(creature, *matter_type, MatterType::AnimalMatter, gained_biomass, flows);
+                    gained_biomass
                 },
                 BiomassConversion::Decomposition { nutrient_output, biomass_gain, matter_type: biomass_type } => {
                     // Decomposers produce soil nutrients and gain biomass
                     let nutrients_produced = (*amount as f32 * nutrient_output) as u32;
+                    let biomass_gain = (biomass_gain * efficiency_trait).min(1.0 - nutrient_output).max(0.0);
                     let biomass_gained = (*amount as f32 * biomass_gain) as u32;
                     
-                    // Add nutrients back to ecosystem
+                    // Add nutrients back to the decomposer's tile
                     if nutrients_produced > 0 {
                         ecosystem_matter.add_matter(MatterType::SoilNutrients, nutrients_produced);
                     }
                     
-                    if biomass_gained > 0 {
-                        creature.biomass.add_matter(*biomass_type, biomass_gained);
-                        
-                        events.push(LifecycleEvent::MatterTransformed {
-                            from_type: *matter_type,
-                            to_type: *biomass_type,
-                            amount: biomass_gained,
-                        });
-                    }
+                    Self::gain_biomass(creature, *matter_type, *biomass_type, biomass_gained, flows);
+                    nutrients_produced + biomass_gained
                 },
+            };
+
+            let left_over = amount.saturating_sub(kept);
+            if left_over > 0 {
+                unused.push((*matter_type, left_over));
             }
         }
 
         Ok(())
     }
 
+    /// Leave `count` creatures' daily waste on their tile, made from what they didn't keep of today's meals
+    /// Waste can't exceed what went unused, so a creature that keeps all of its food leaves none
+    fn excrete(
+        excretion: &[(MatterType, u32)],
+        count: u32,
+        unused: &mut [(MatterType, u32)],
+        flows: &mut MatterFlows,
+        ecosystem_matter: &mut EcosystemMatter,
+    ) {
+        for (waste_type, amount) in excretion {
+            let mut wanted = amount * count;
+            for (from_type, available) in unused.iter_mut() {
+                let excreted = wanted.min(*available);
+                if excreted == 0 {
+                    continue;
+                }
+                *available -= excreted;
+                wanted -= excreted;
+                ecosystem_matter.add_matter(*waste_type, excreted);
+                flows.add(*from_type, *waste_type, excreted);
+            }
+        }
+    }
+
+    /// Add biomass built from eaten matter to a creature, recording each component it grows
+    fn gain_biomass(
+        creature: &mut crate::gameplay::lifecycle::IndividualCreature,
+        from_type: MatterType,
+        built_type: MatterType,
+        amount: u32,
+        flows: &mut MatterFlows,
+    ) {
+        for (to_type, gained) in creature.build_biomass(built_type, amount) {
+            flows.add(from_type, to_type, gained);
+        }
+    }
+
+    /// Feeding demand of a living, active creature or cohort on one of its diets
+    /// Dormant creatures and stages that don't feed sit out feeding entirely, so they are never reported as fed
+    fn demand_for(&self, ecosystem: &EcosystemPopulation, creature_id: CreatureId, diet: usize, share: f32) -> Option<FeedingDemand> {
+        if let Some(creature) = ecosystem.creatures.get(creature_id).filter(|c| c.is_alive() && c.feeds()) {
+            self.calculate_feeding_demand(creature, 1, diet, share)
+        } else if let Some(cohort) = ecosystem.cohort(creature_id).filter(|c| c.member.feeds()) {
+            self.calculate_feeding_demand(&cohort.member, cohort.count, diet, share)
+        } else {
+            None
+        }
+    }
+
+    /// Whether a species eats living animal matter on any of its diets
+    fn is_predator(species: &Species) -> bool {
+        species.feeding_requirements.diets().any(|(requirements, _)| requirements.contains_key(&MatterType::AnimalMatter))
+    }
+
+    /// Predators whose tile's animal matter can't cover their preferred diet catch prey living on that tile:
+    /// animals that don't eat animal matter themselves, oldest first, until the shortfall is covered
+    /// A catch's animal biomass joins the tile's pool for the predators to compete for; the rest of its body is left as dead matter
+    fn hunt(&self, creature_ids: &[CreatureId], ecosystem: &mut EcosystemPopulation) -> Result<Vec<LifecycleEvent>, PhaseError> {
+        let mut shortfall: BTreeMap<TilePosition, u32> = BTreeMap::new();
+        for creature_id in creature_ids {
+            let species = ecosystem.creatures.get(*creature_id)
+                .map(|creature| &creature.species)
+                .or_else(|| ecosystem.cohort(*creature_id).map(|cohort| &cohort.member.species));
+            if !species.is_some_and(|species| species.feeding_requirements.base_requirements.contains_key(&MatterType::AnimalMatter)) {
+                continue;
+            }
+            let Some(demand) = self.demand_for(ecosystem, *creature_id, 0, 1.0) else {
+                continue;
+            };
+            for (matter_type, amount) in &demand.matter_demands {
+                if *matter_type == MatterType::AnimalMatter {
+                    *shortfall.entry(demand.tile).or_insert(0) += amount;
+                }
+            }
+        }
+        shortfall.retain(|tile, wanted| {
+            *wanted = wanted.saturating_sub(ecosystem.grid.tile(*tile).get_amount(MatterType::AnimalMatter));
+            *wanted > 0
+        });
+        if shortfall.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let is_prey = |species: &Species| species.kingdom == Kingdom::Animal && !Self::is_predator(species);
+        let mut events = Vec::new();
+        let mut caught: Vec<(TilePosition, u32)> = Vec::new();
+
+        for creature in ecosystem.creatures.iter_mut() {
+            let Some(wanted) = shortfall.get_mut(&creature.tile) else {
+                continue;
+            };
+            if *wanted == 0 || !creature.is_alive() || creature.biomass.animal_matter == 0 || !is_prey(&creature.species) {
+                continue;
+            }
+            let animal_matter = std::mem::take(&mut creature.biomass.animal_matter);
+            *wanted = wanted.saturating_sub(animal_matter);
+            caught.push((creature.tile, animal_matter));
+            creature.die(DeathCause::Predation);
+            events.push(LifecycleEvent::CreatureDied { creature_id: creature.id, cause: DeathCause::Predation });
+        }
+
+        let mut cohort_deaths = Vec::new();
+        for cohort in &mut ecosystem.cohorts {
+            let Some(wanted) = shortfall.get_mut(&cohort.member.tile) else {
+                continue;
+            };
+            let per_member = cohort.member.biomass.animal_matter;
+            if *wanted == 0 || per_member == 0 || !is_prey(&cohort.member.species) {
+                continue;
+            }
+            let count = wanted.div_ceil(per_member).min(cohort.count);
+            *wanted = wanted.saturating_sub(per_member * count);
+            cohort.count -= count;
+            caught.push((cohort.member.tile, per_member * count));
+
+            // The rest of the catch's bodies are left where they were caught
+            let remains = cohort.member.biomass.plant_matter * count;
+            ecosystem.grid.tile_mut(cohort.member.tile).add_matter(MatterType::DeadPlantMatter, remains);
+            cohort_deaths.push((cohort.member.species.name, count));
+            events.push(LifecycleEvent::CohortDied {
+                cohort_id: cohort.id(),
+                species: cohort.member.species.name,
+                cause: DeathCause::Predation,
+                count,
+            });
+        }
+
+        for (tile, animal_matter) in caught {
+            ecosystem.grid.tile_mut(tile).add_matter(MatterType::AnimalMatter, animal_matter);
+        }
+        for (species, count) in cohort_deaths {
+            ecosystem.record_deaths(species, &DeathCause::Predation, count);
+        }
+        ecosystem.cohorts.retain(|cohort| cohort.count > 0);
+        ecosystem.remove_dead_creatures(self.phase_name())?;
+
+        Ok(events)
+    }
+
     /// Process feeding for creatures of a specific trophic level
     fn process_trophic_level(
         &self,
         creature_ids: Vec<CreatureId>,
+        policy: &dyn FeedingAllocationPolicy,
+        symbiosis: &SymbiosisMap,
         ecosystem: &mut EcosystemPopulation,
+        flows: &mut MatterFlows,
     ) -> Result<Vec<LifecycleEvent>, PhaseError> {
-        let mut events = Vec::new();
-
-        // Calculate demands for all creatures in this trophic level
-        let mut demands = Vec::new();
-        for creature_id in &creature_ids {
-            if let Some(creature) = ecosystem.creatures.iter().find(|c| c.id == *creature_id && c.is_alive()) {
-                demands.push(self.calculate_feeding_demand(creature));
-            }
+        // Calculate demands for all creatures in this trophic level, on their preferred diets
+        // Each demand carries the allocation it adds to, once the creature has eaten from an earlier diet
+        let mut pending: Vec<(FeedingDemand, Option<usize>)> = creature_ids.iter()
+            .filter_map(|creature_id| self.demand_for(ecosystem, *creature_id, 0, 1.0))
+            .map(|demand| (demand, None))
+            .collect();
+
+        if pending.is_empty() {
+            return Ok(Vec::new());
         }
 
-        if demands.is_empty() {
-            return Ok(events);
-        }
+        // Allocate resources among competing creatures: everyone eats their preferred diet first,
+        // then those still hungry try each fallback diet in turn for the share of their need left unmet
+        let day = ecosystem.current_day;
+        let mut allocations: Vec<FeedingAllocation> = Vec::with_capacity(pending.len());
+        while !pending.is_empty() {
+            let (demands, targets): (Vec<FeedingDemand>, Vec<Option<usize>>) = pending.into_iter().unzip();
+            let fallbacks: Vec<(usize, bool)> = demands.iter().map(|demand| (demand.diet + 1, demand.has_fallback)).collect();
+            let results = self.allocate_resources(demands, policy, day, &mut ecosystem.grid);
+
+            pending = Vec::new();
+            for ((result, target), (next_diet, has_fallback)) in results.into_iter().zip(targets).zip(fallbacks) {
+                let index = match target {
+                    Some(index) => {
+                        let allocation = &mut allocations[index];
+                        allocation.allocated_resources.extend(result.allocated_resources);
+                        allocation.satisfaction_level = (allocation.satisfaction_level + result.satisfaction_level).min(1.0);
+                        index
+                    }
+                    None => {
+                        allocations.push(result);
+                        allocations.len() - 1
+                    }
+                };
 
-        // Allocate resources among competing creatures
-        let allocations = self.allocate_resources(demands, &mut ecosystem.ecosystem_matter);
+                let unmet = 1.0 - allocations[index].satisfaction_level;
+                if has_fallback && unmet > f32::EPSILON {
+                    if let Some(demand) = self.demand_for(ecosystem, allocations[index].creature_id, next_diet, unmet) {
+                        pending.push((demand, Some(index)));
+                    }
+                }
+            }
+        }
 
         // Apply feeding results
-        let feeding_events = self.apply_feeding_results(allocations, ecosystem)?;
-        events.extend(feeding_events);
-
-        Ok(events)
+        self.apply_feeding_results(allocations, symbiosis, ecosystem, flows)
     }
 }
 
@@ -275,63 +618,47 @@ impl LifecyclePhase for FeedingPhaseImpl {
     fn execute(&self, ecosystem: &mut EcosystemPopulation) -> Result<PhaseResult, PhaseError> {
         let mut all_events = Vec::new();
         let mut creatures_processed = 0;
-        let mut matter_transformed = 0;
+        let mut flows = MatterFlows::default();
 
         // Collect creatures grouped by trophic level to avoid borrowing issues
-        let mut trophic_groups: Vec<(u8, Vec<CreatureId>)> = Vec::new();
-        let mut current_trophic_level = None;
-        let mut current_group = Vec::new();
-
-        // Get creatures in trophic order
-        for creature in ecosystem.creatures_by_trophic_order() {
-            let trophic_level = match creature.species.kingdom {
-                crate::gameplay::species::Kingdom::Fungi => 0,
-                crate::gameplay::species::Kingdom::Animal => {
-                    if creature.species.name.contains("Earthworm") || creature.species.name.contains("Worm") { 0 } else { 2 }
-                },
-                crate::gameplay::species::Kingdom::Plant => 1,
-            };
-
-            match current_trophic_level {
-                None => {
-                    current_trophic_level = Some(trophic_level);
-                    current_group.push(creature.id);
-                }
-                Some(level) if level == trophic_level => {
-                    current_group.push(creature.id);
-                }
-                Some(level) => {
-                    // Finish current group and start new one
-                    trophic_groups.push((level, current_group.clone()));
-                    current_group.clear();
-                    current_group.push(creature.id);
-                    current_trophic_level = Some(trophic_level);
-                }
+        // Cohorts compete alongside individuals of the same level, after them
+        let mut trophic_groups = ecosystem.living_trophic_groups();
+        for cohort in &ecosystem.cohorts {
+            let level = trophic_level(&cohort.member.species);
+            match trophic_groups.iter_mut().find(|(group_level, _)| *group_level == level) {
+                Some((_, ids)) => ids.push(cohort.id()),
+                None => trophic_groups.push((level, vec![cohort.id()])),
             }
         }
-
-        // Add final group
-        if !current_group.is_empty() {
-            if let Some(level) = current_trophic_level {
-                trophic_groups.push((level, current_group));
+        trophic_groups.sort_by_key(|(level, _)| *level);
+
+        // Use the phase's own policy if it has one, otherwise the ecosystem's selection
+        let selected_policy;
+        let policy: &dyn FeedingAllocationPolicy = match &self.policy {
+            Some(policy) => policy.as_ref(),
+            None => {
+                selected_policy = ecosystem.feeding_policy.policy();
+                selected_policy.as_ref()
             }
-        }
+        };
+
+        // Feeding boosts from nearby partners are settled before anyone eats
+        let symbiosis = SymbiosisMap::build(ecosystem);
 
         // Process each trophic group in order
         for (_level, creature_ids) in trophic_groups {
-            let group_events = self.process_trophic_level(creature_ids.clone(), ecosystem)?;
-            creatures_processed += creature_ids.len() as u32;
-            
-            // Count matter transformation events
-            matter_transformed += group_events.iter()
-                .filter(|e| matches!(e, LifecycleEvent::MatterTransformed { .. }))
-                .count() as u32;
+            // Hungry predators hunt before their level competes for food
+            all_events.extend(self.hunt(&creature_ids, ecosystem)?);
+            let group_events = self.process_trophic_level(creature_ids.clone(), policy, &symbiosis, ecosystem, &mut flows)?;
+            creatures_processed += creature_ids.iter()
+                .map(|id| ecosystem.cohort(*id).map_or(1, |cohort| cohort.count))
+                .sum::<u32>();
             
             all_events.extend(group_events);
         }
 
-        println!("Info: Feeding phase completed: {} creatures processed, {} matter transformations", 
-                 creatures_processed, matter_transformed);
+        let matter_transformed = flows.total();
+        all_events.extend(flows.into_events());
 
         Ok(PhaseResult {
             creatures_processed,
@@ -340,16 +667,24 @@ impl LifecyclePhase for FeedingPhaseImpl {
         })
     }
 
-    fn validate_preconditions(&self, _ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
-        // Validate that death phase has been completed
-        // (no creatures should be marked as dead but still in population)
-        Ok(())
+    fn validate_preconditions(&self, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+        if !INVARIANT_CHECKS_ENABLED {
+            return Ok(());
+        }
+
+        // Death phase must have cleared out the dead before anyone competes for food
+        invariants::check_no_dead_creatures(self.phase_name(), ecosystem)?;
+        invariants::check_population_invariants(self.phase_name(), ecosystem)
     }
 
-    fn validate_postconditions(&self, _ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
-        // Validate matter conservation
+    fn validate_postconditions(&self, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+        if !INVARIANT_CHECKS_ENABLED {
+            return Ok(());
+        }
+
         // All creatures should have updated feeding status
-        Ok(())
+        invariants::check_fed_status_fresh(self.phase_name(), ecosystem)?;
+        invariants::check_population_invariants(self.phase_name(), ecosystem)
     }
 
     fn phase_name(&self) -> &'static str {
@@ -362,3 +697,107 @@ impl Default for FeedingPhaseImpl {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::lifecycle::MaturityStage;
+    use crate::gameplay::species::{get_species, SpeciesColor};
+
+    /// A frog and a rabbit sharing the centre tile of an otherwise empty garden
+    fn frog_and_rabbit(animal_matter: u32) -> (EcosystemPopulation, CreatureId, CreatureId) {
+        let mut ecosystem = EcosystemPopulation::default();
+        for tile in ecosystem.grid.tiles_mut() {
+            for matter_type in MatterType::all() {
+                tile.set_amount(matter_type, 0);
+            }
+        }
+        let center = ecosystem.grid.center();
+        ecosystem.grid.tile_mut(center).set_amount(MatterType::AnimalMatter, animal_matter);
+
+        let frog = ecosystem.add_mature_creature(get_species("Frog").unwrap().clone(), (0, 4));
+        let rabbit = ecosystem.add_mature_creature(get_species("Rabbit").unwrap().clone(), (0, 5));
+        (ecosystem, frog, rabbit)
+    }
+
+    #[test]
+    fn hungry_predators_catch_prey_on_their_tile() {
+        let (mut ecosystem, frog, rabbit) = frog_and_rabbit(0);
+
+        let result = FeedingPhaseImpl::new().execute(&mut ecosystem).unwrap();
+
+        assert!(!ecosystem.creatures.contains(rabbit));
+        assert!(result.events.iter().any(|event| matches!(
+            event,
+            LifecycleEvent::CreatureDied { creature_id, cause: DeathCause::Predation } if *creature_id == rabbit
+        )));
+        assert_eq!(ecosystem.mortality_by_species["Rabbit"].count(&DeathCause::Predation), 1);
+        assert!(matches!(ecosystem.creatures.get(frog).unwrap().fed_status, FeedingResult::FullyFed));
+    }
+
+    #[test]
+    fn predators_with_enough_food_leave_prey_alone() {
+        let (mut ecosystem, _, rabbit) = frog_and_rabbit(100);
+
+        FeedingPhaseImpl::new().execute(&mut ecosystem).unwrap();
+
+        assert!(ecosystem.creatures.contains(rabbit));
+        assert!(!ecosystem.mortality_by_species.contains_key("Rabbit"));
+    }
+
+    /// An omnivore on the centre tile that eats 2 animal matter, or else 3 plant matter
+    fn omnivore(animal_matter: u32, plant_matter: u32) -> (EcosystemPopulation, CreatureId) {
+        let mut ecosystem = EcosystemPopulation::default();
+        let center = ecosystem.grid.center();
+        let tile = ecosystem.grid.tile_mut(center);
+        tile.set_amount(MatterType::AnimalMatter, animal_matter);
+        tile.set_amount(MatterType::PlantMatter, plant_matter);
+
+        let species = Species::new("Omnivore", Kingdom::Animal, 1, 4, SpeciesColor::srgb(0.5, 0.5, 0.5))
+            .with_feeding_requirement(MatterType::AnimalMatter, 2)
+            .with_biomass_conversion(BiomassConversion::AnimalToAnimal { efficiency: 0.6 })
+            .with_alternative_diet(&[(MatterType::PlantMatter, 3)], BiomassConversion::PlantToAnimal { efficiency: 0.5 });
+        let omnivore = ecosystem.add_mature_creature(species, (0, 4));
+        (ecosystem, omnivore)
+    }
+
+    #[test]
+    fn omnivores_make_up_a_shortfall_from_their_fallback_diet() {
+        let (mut ecosystem, omnivore) = omnivore(1, 100);
+
+        FeedingPhaseImpl::new().execute(&mut ecosystem).unwrap();
+
+        // Half a meal of animal matter leaves half of the 3 plant matter to find, rounded up
+        let center = ecosystem.grid.tile(ecosystem.grid.center());
+        assert_eq!(center.get_amount(MatterType::AnimalMatter), 0);
+        assert_eq!(center.get_amount(MatterType::PlantMatter), 98);
+        assert!(matches!(ecosystem.creatures.get(omnivore).unwrap().fed_status, FeedingResult::FullyFed));
+    }
+
+    #[test]
+    fn omnivores_prefer_their_first_diet() {
+        let (mut ecosystem, omnivore) = omnivore(100, 100);
+
+        FeedingPhaseImpl::new().execute(&mut ecosystem).unwrap();
+
+        let center = ecosystem.grid.tile(ecosystem.grid.center());
+        assert_eq!(center.get_amount(MatterType::PlantMatter), 100);
+        assert!(matches!(ecosystem.creatures.get(omnivore).unwrap().fed_status, FeedingResult::FullyFed));
+    }
+
+    #[test]
+    fn seeds_sit_out_feeding_even_when_awake() {
+        let mut ecosystem = EcosystemPopulation::default();
+        let clover = get_species("Clover").unwrap().clone().with_seed_stage(3);
+        let seed = ecosystem.add_creature(clover, (1, 0));
+        assert_eq!(ecosystem.creatures.get(seed).unwrap().maturity_stage, MaturityStage::Seed);
+        assert!(!ecosystem.creatures.get(seed).unwrap().dormant);
+
+        let result = FeedingPhaseImpl::new().execute(&mut ecosystem).unwrap();
+
+        let seed = ecosystem.creatures.get(seed).unwrap();
+        assert!(matches!(seed.fed_status, FeedingResult::Starving));
+        assert_eq!(seed.fed_on_day, None);
+        assert!(!result.events.iter().any(|event| matches!(event, LifecycleEvent::CreatureFed { .. })));
+    }
+}
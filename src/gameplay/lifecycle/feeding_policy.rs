@@ -0,0 +1,345 @@
+use super::{CreatureId, MatterType};
+
+/// A single creature's request for one matter type during feeding
+#[derive(Clone, Debug)]
+pub struct FeedingRequest {
+    pub creature_id: CreatureId,
+    pub amount: u32,
+    pub introduction_day: u32,
+    pub introduction_order: u32,
+    pub is_mature: bool,
+    /// Species feeding priority (higher feeds first)
+    pub priority: i32,
+}
+
+/// Context for an allocation decision
+#[derive(Clone, Copy, Debug)]
+pub struct AllocationContext {
+    pub day: u32,
+    pub matter_type: MatterType,
+}
+
+/// Strategy for splitting a scarce matter pool among competing creatures
+pub trait FeedingAllocationPolicy: Send + Sync {
+    /// Split `available` among requests whose combined demand exceeds it
+    /// Returns the amount granted to each request, in request order
+    fn allocate(&self, available: u32, requests: &[FeedingRequest], context: &AllocationContext) -> Vec<u32>;
+
+    /// Get the name of this policy for logging/debugging
+    fn policy_name(&self) -> &'static str;
+}
+
+/// Allocate a matter pool, serving species feeding priority tiers from highest to lowest
+/// Demand is met in full when possible; the policy only decides how the contested tier is split
+pub fn allocate_by_priority(
+    policy: &dyn FeedingAllocationPolicy,
+    available: u32,
+    requests: &[FeedingRequest],
+    context: &AllocationContext,
+) -> Vec<u32> {
+    let mut granted = vec![0; requests.len()];
+
+    let total_demand: u32 = requests.iter().map(|r| r.amount).sum();
+    if total_demand <= available {
+        for (grant, request) in granted.iter_mut().zip(requests) {
+            *grant = request.amount;
+        }
+        return granted;
+    }
+
+    let mut priorities: Vec<i32> = requests.iter().map(|r| r.priority).collect();
+    priorities.sort_unstable_by(|a, b| b.cmp(a));
+    priorities.dedup();
+
+    let mut remaining = available;
+    for priority in priorities {
+        let tier: Vec<usize> = (0..requests.len()).filter(|&i| requests[i].priority == priority).collect();
+        let tier_demand: u32 = tier.iter().map(|&i| requests[i].amount).sum();
+
+        if tier_demand <= remaining {
+            for &i in &tier {
+                granted[i] = requests[i].amount;
+            }
+            remaining -= tier_demand;
+        } else {
+            let tier_requests: Vec<FeedingRequest> = tier.iter().map(|&i| requests[i].clone()).collect();
+            let tier_grants = policy.allocate(remaining, &tier_requests, context);
+            for (&i, grant) in tier.iter().zip(tier_grants) {
+                granted[i] = grant.min(requests[i].amount);
+            }
+            // Lower priority tiers get nothing once a tier is contested
+            break;
+        }
+    }
+
+    granted
+}
+
+/// Serve requests fully in the given order until the pool runs out
+fn fill_in_order(available: u32, requests: &[FeedingRequest], order: &[usize]) -> Vec<u32> {
+    let mut granted = vec![0; requests.len()];
+    let mut remaining = available;
+
+    for &i in order {
+        let grant = requests[i].amount.min(remaining);
+        granted[i] = grant;
+        remaining -= grant;
+    }
+
+    granted
+}
+
+/// Indices of requests sorted by arrival in the garden (day, then order within the day)
+fn arrival_order(requests: &[FeedingRequest]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..requests.len()).collect();
+    order.sort_by_key(|&i| (requests[i].introduction_day, requests[i].introduction_order, requests[i].creature_id));
+    order
+}
+
+/// Earliest arrivals eat first
+pub struct FirstComePolicy;
+
+impl FeedingAllocationPolicy for FirstComePolicy {
+    fn allocate(&self, available: u32, requests: &[FeedingRequest], _context: &AllocationContext) -> Vec<u32> {
+        fill_in_order(available, requests, &arrival_order(requests))
+    }
+
+    fn policy_name(&self) -> &'static str {
+        "First Come"
+    }
+}
+
+/// Everyone receives the same fraction of their request
+/// Units lost to rounding go to the largest remainders, earliest arrivals breaking ties
+pub struct ProportionalSharePolicy;
+
+impl FeedingAllocationPolicy for ProportionalSharePolicy {
+    fn allocate(&self, available: u32, requests: &[FeedingRequest], _context: &AllocationContext) -> Vec<u32> {
+        let total_demand: u64 = requests.iter().map(|r| r.amount as u64).sum();
+        if total_demand == 0 {
+            return vec![0; requests.len()];
+        }
+
+        let mut granted = Vec::with_capacity(requests.len());
+        let mut remainders = Vec::with_capacity(requests.len());
+        for request in requests {
+            let share = request.amount as u64 * available as u64;
+            granted.push((share / total_demand) as u32);
+            remainders.push(share % total_demand);
+        }
+
+        let mut leftover = available - granted.iter().sum::<u32>();
+        let mut order = arrival_order(requests);
+        order.sort_by(|&a, &b| remainders[b].cmp(&remainders[a]));
+        for i in order {
+            if leftover == 0 {
+                break;
+            }
+            if granted[i] < requests[i].amount {
+                granted[i] += 1;
+                leftover -= 1;
+            }
+        }
+
+        granted
+    }
+
+    fn policy_name(&self) -> &'static str {
+        "Proportional Share"
+    }
+}
+
+/// Juveniles eat before adults, each group by arrival
+pub struct JuvenilesFirstPolicy;
+
+impl FeedingAllocationPolicy for JuvenilesFirstPolicy {
+    fn allocate(&self, available: u32, requests: &[FeedingRequest], _context: &AllocationContext) -> Vec<u32> {
+        let mut order = arrival_order(requests);
+        order.sort_by_key(|&i| requests[i].is_mature);
+        fill_in_order(available, requests, &order)
+    }
+
+    fn policy_name(&self) -> &'static str {
+        "Juveniles First"
+    }
+}
+
+/// Adults eat before juveniles, each group by arrival
+pub struct MatureFirstPolicy;
+
+impl FeedingAllocationPolicy for MatureFirstPolicy {
+    fn allocate(&self, available: u32, requests: &[FeedingRequest], _context: &AllocationContext) -> Vec<u32> {
+        let mut order = arrival_order(requests);
+        order.sort_by_key(|&i| !requests[i].is_mature);
+        fill_in_order(available, requests, &order)
+    }
+
+    fn policy_name(&self) -> &'static str {
+        "Mature First"
+    }
+}
+
+/// Feeding order is drawn at random each day, reproducible for a given seed
+pub struct RandomLotteryPolicy {
+    pub seed: u64,
+}
+
+impl FeedingAllocationPolicy for RandomLotteryPolicy {
+    fn allocate(&self, available: u32, requests: &[FeedingRequest], context: &AllocationContext) -> Vec<u32> {
+        let day_seed = self.seed
+            ^ (context.day as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+            ^ context.matter_type as u64;
+        let mut rng = fastrand::Rng::with_seed(day_seed);
+
+        let mut order = arrival_order(requests);
+        rng.shuffle(&mut order);
+        fill_in_order(available, requests, &order)
+    }
+
+    fn policy_name(&self) -> &'static str {
+        "Random Lottery"
+    }
+}
+
+/// Selectable feeding policy for a game or round
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum FeedingPolicyKind {
+    #[default]
+    FirstCome,
+    ProportionalShare,
+    JuvenilesFirst,
+    MatureFirst,
+    RandomLottery { seed: u64 },
+}
+
+impl FeedingPolicyKind {
+    /// Build the allocation policy for this selection
+    pub fn policy(&self) -> Box<dyn FeedingAllocationPolicy> {
+        match self {
+            FeedingPolicyKind::FirstCome => Box::new(FirstComePolicy),
+            FeedingPolicyKind::ProportionalShare => Box::new(ProportionalSharePolicy),
+            FeedingPolicyKind::JuvenilesFirst => Box::new(JuvenilesFirstPolicy),
+            FeedingPolicyKind::MatureFirst => Box::new(MatureFirstPolicy),
+            FeedingPolicyKind::RandomLottery { seed } => Box::new(RandomLotteryPolicy { seed: *seed }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::lifecycle::{EcosystemPopulation, FeedingPhaseImpl, LifecycleEvent, LifecyclePhase};
+    use crate::gameplay::species::get_species;
+
+    const CONTEXT: AllocationContext = AllocationContext { day: 3, matter_type: MatterType::PlantMatter };
+
+    fn request(creature_id: CreatureId, amount: u32, introduction_day: u32, is_mature: bool, priority: i32) -> FeedingRequest {
+        FeedingRequest { creature_id, amount, introduction_day, introduction_order: 0, is_mature, priority }
+    }
+
+    #[test]
+    fn demand_is_met_in_full_when_the_pool_is_large_enough() {
+        let requests = [request(1, 4, 0, false, 0), request(2, 6, 0, false, 1)];
+        assert_eq!(allocate_by_priority(&FirstComePolicy, 10, &requests, &CONTEXT), vec![4, 6]);
+    }
+
+    #[test]
+    fn higher_priority_tiers_eat_first_and_lower_tiers_go_without() {
+        let requests = [
+            request(1, 5, 0, false, 0),
+            request(2, 3, 0, false, 1),
+            request(3, 4, 0, false, 2),
+            request(4, 3, 1, false, 1),
+        ];
+        // The top tier is served in full, the middle tier splits what is left, the bottom gets nothing
+        assert_eq!(allocate_by_priority(&FirstComePolicy, 8, &requests, &CONTEXT), vec![0, 3, 4, 1]);
+    }
+
+    #[test]
+    fn first_come_serves_earliest_arrivals() {
+        let requests = [request(1, 5, 2, false, 0), request(2, 5, 0, false, 0), request(3, 5, 1, false, 0)];
+        assert_eq!(allocate_by_priority(&FirstComePolicy, 7, &requests, &CONTEXT), vec![0, 5, 2]);
+    }
+
+    #[test]
+    fn first_come_breaks_same_day_ties_by_introduction_order() {
+        let mut requests = [request(1, 5, 0, false, 0), request(2, 5, 0, false, 0)];
+        requests[0].introduction_order = 1;
+        assert_eq!(allocate_by_priority(&FirstComePolicy, 5, &requests, &CONTEXT), vec![0, 5]);
+    }
+
+    #[test]
+    fn proportional_share_gives_everyone_the_same_fraction() {
+        let requests = [request(1, 4, 0, false, 0), request(2, 8, 0, false, 0)];
+        assert_eq!(allocate_by_priority(&ProportionalSharePolicy, 6, &requests, &CONTEXT), vec![2, 4]);
+    }
+
+    #[test]
+    fn proportional_share_hands_rounding_remainders_to_the_largest() {
+        // Exact shares are 2.5, 3.33 and 4.17
+        let requests = [request(1, 3, 0, false, 0), request(2, 4, 0, false, 0), request(3, 5, 0, false, 0)];
+        assert_eq!(allocate_by_priority(&ProportionalSharePolicy, 10, &requests, &CONTEXT), vec![3, 3, 4]);
+    }
+
+    #[test]
+    fn proportional_share_breaks_remainder_ties_by_arrival() {
+        let requests = [request(1, 1, 2, false, 0), request(2, 1, 0, false, 0), request(3, 1, 1, false, 0)];
+        assert_eq!(allocate_by_priority(&ProportionalSharePolicy, 2, &requests, &CONTEXT), vec![0, 1, 1]);
+    }
+
+    #[test]
+    fn juveniles_first_feeds_juveniles_before_earlier_adults() {
+        let requests = [request(1, 4, 0, true, 0), request(2, 4, 1, false, 0)];
+        assert_eq!(allocate_by_priority(&JuvenilesFirstPolicy, 5, &requests, &CONTEXT), vec![1, 4]);
+    }
+
+    #[test]
+    fn mature_first_feeds_adults_before_earlier_juveniles() {
+        let requests = [request(1, 4, 0, false, 0), request(2, 4, 1, true, 0)];
+        assert_eq!(allocate_by_priority(&MatureFirstPolicy, 5, &requests, &CONTEXT), vec![1, 4]);
+    }
+
+    #[test]
+    fn random_lottery_is_reproducible_for_a_seed() {
+        let requests: Vec<FeedingRequest> = (0..8).map(|id| request(id, 3, 0, false, 0)).collect();
+        let policy = RandomLotteryPolicy { seed: 42 };
+
+        let first = allocate_by_priority(&policy, 10, &requests, &CONTEXT);
+        let second = allocate_by_priority(&RandomLotteryPolicy { seed: 42 }, 10, &requests, &CONTEXT);
+
+        assert_eq!(first, second);
+        assert_eq!(first.iter().sum::<u32>(), 10);
+        assert!(first.iter().zip(&requests).all(|(grant, request)| *grant <= request.amount));
+    }
+
+    #[test]
+    fn feeding_phase_policy_overrides_the_ecosystem_selection() {
+        let rabbit = get_species("Rabbit").unwrap().clone();
+        let mut ecosystem = EcosystemPopulation { feeding_policy: FeedingPolicyKind::FirstCome, ..Default::default() };
+        let juvenile = ecosystem.add_creature(rabbit.clone(), rabbit.starting_biomass());
+        let adult = ecosystem.add_mature_creature(rabbit.clone(), rabbit.starting_biomass());
+
+        // Only one unit of food in the whole garden, on the rabbits' own tile
+        for tile in ecosystem.grid.tiles_mut() {
+            for matter_type in MatterType::all() {
+                tile.set_amount(matter_type, 0);
+            }
+        }
+        let center = ecosystem.grid.center();
+        ecosystem.grid.tile_mut(center).set_amount(MatterType::PlantMatter, 1);
+
+        let fed = |phase: FeedingPhaseImpl| {
+            let mut ecosystem = ecosystem.clone();
+            let result = phase.execute(&mut ecosystem).unwrap();
+            result.events.iter().filter_map(|event| match event {
+                LifecycleEvent::CreatureFed { creature_id, satisfaction } if *satisfaction > 0.0 => Some(*creature_id),
+                _ => None,
+            }).collect::<Vec<_>>()
+        };
+
+        // The ecosystem's first-come selection feeds the earlier arrival
+        assert_eq!(fed(FeedingPhaseImpl::new()), vec![juvenile]);
+        // The phase's own policy wins over it
+        assert_eq!(fed(FeedingPhaseImpl::with_policy(Box::new(MatureFirstPolicy))), vec![adult]);
+    }
+}
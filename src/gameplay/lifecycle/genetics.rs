@@ -0,0 +1,298 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use crate::gameplay::species::Species;
+
+/// Lowest and highest value a trait multiplier can drift to
+pub const TRAIT_RANGE: (f32, f32) = (0.5, 2.0);
+
+/// Heritable traits of an individual, each a multiplier on its species' parameter (1.0 = the species' own value)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Genome {
+    /// Scales how much biomass the creature gains from what it eats (never past 100% of the meal)
+    pub feeding_efficiency: f32,
+    /// Scales the age at which the creature dies of old age
+    pub lifespan: f32,
+    /// Scales the days the creature waits between litters
+    pub reproduction_cooldown: f32,
+    /// Scales how much the creature eats and how long it can go hungry
+    pub size: f32,
+}
+
+impl Default for Genome {
+    fn default() -> Self {
+        Self::from_traits([1.0; 4])
+    }
+}
+
+impl Genome {
+    pub fn trait_names() -> [&'static str; 4] {
+        ["Feeding Efficiency", "Lifespan", "Reproduction Cooldown", "Size"]
+    }
+
+    /// Trait values in `trait_names()` order
+    pub fn traits(&self) -> [f32; 4] {
+        [self.feeding_efficiency, self.lifespan, self.reproduction_cooldown, self.size]
+    }
+
+    fn from_traits(traits: [f32; 4]) -> Self {
+        let [feeding_efficiency, lifespan, reproduction_cooldown, size] = traits;
+        Self { feeding_efficiency, lifespan, reproduction_cooldown, size }
+    }
+
+    /// Copy of this genome with every trait nudged by up to `rate` (as a fraction) in either direction
+    pub fn mutated(&self, rng: &mut fastrand::Rng, rate: f32) -> Genome {
+        Self::from_traits(self.traits().map(|value| {
+            let change = 1.0 + (rng.f32() * 2.0 - 1.0) * rate;
+            (value * change).clamp(TRAIT_RANGE.0, TRAIT_RANGE.1)
+        }))
+    }
+
+    /// Largest difference between any two matching traits
+    pub fn divergence(&self, other: &Genome) -> f32 {
+        self.traits()
+            .into_iter()
+            .zip(other.traits())
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0, f32::max)
+    }
+
+    /// Count-weighted mean of two groups' genomes, used for a species' mean traits and a cohort's members
+    pub fn blend(&self, count: u32, other: &Genome, other_count: u32) -> Genome {
+        let total = (count + other_count).max(1) as f32;
+        let mut traits = self.traits();
+        for (value, other_value) in traits.iter_mut().zip(other.traits()) {
+            *value = (*value * count as f32 + other_value * other_count as f32) / total;
+        }
+        Self::from_traits(traits)
+    }
+}
+
+/// Apply a trait multiplier to a whole-number species parameter
+pub fn scale_trait(base: u32, multiplier: f32) -> u32 {
+    (base as f32 * multiplier).round() as u32
+}
+
+/// A species' mean traits on one day
+#[derive(Clone, Debug)]
+pub struct TraitSample {
+    pub day: u32,
+    pub population: u32,
+    pub mean: Genome,
+}
+
+/// Daily trait means per species, and which variant species branched from which
+#[derive(Clone, Debug, Default)]
+pub struct LineageTracker {
+    history: HashMap<&'static str, Vec<TraitSample>>,
+    parents: HashMap<&'static str, &'static str>,
+}
+
+impl LineageTracker {
+    /// Record a species' trait means for a day
+    pub fn record(&mut self, species: &'static str, sample: TraitSample) {
+        self.history.entry(species).or_default().push(sample);
+    }
+
+    /// Every recorded sample for a species, oldest first
+    pub fn history(&self, species: &str) -> &[TraitSample] {
+        self.history.get(species).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// How far each trait mean has moved since the species was first recorded, in `Genome::trait_names()` order
+    pub fn drift(&self, species: &str) -> Option<[f32; 4]> {
+        let history = self.history(species);
+        let (first, last) = (history.first()?, history.last()?);
+        let mut drift = last.mean.traits();
+        for (value, start) in drift.iter_mut().zip(first.mean.traits()) {
+            *value -= start;
+        }
+        Some(drift)
+    }
+
+    /// The species a variant branched from
+    pub fn parent_of(&self, species: &str) -> Option<&'static str> {
+        self.parents.get(species).copied()
+    }
+
+    /// The original species a (possibly many times removed) variant descends from
+    pub fn root_of<'a>(&self, species: &'a str) -> &'a str {
+        let mut root = species;
+        while let Some(parent) = self.parent_of(root) {
+            root = parent;
+        }
+        root
+    }
+
+    /// Every variant founded so far
+    pub fn variants(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.parents.keys().copied()
+    }
+
+    /// Variants that branched directly from a species, in name order
+    pub fn variants_of(&self, species: &str) -> Vec<&'static str> {
+        let mut variants: Vec<_> = self.parents
+            .iter()
+            .filter(|(_, parent)| **parent == species)
+            .map(|(variant, _)| *variant)
+            .collect();
+        variants.sort();
+        variants
+    }
+}
+
+/// When a lineage has drifted this far from its species' founders, it becomes a new variant species
+#[derive(Clone, Debug)]
+pub struct SpeciationRule {
+    /// Largest single-trait difference from the founding genome that triggers a split
+    pub divergence_threshold: f32,
+}
+
+impl Default for SpeciationRule {
+    fn default() -> Self {
+        Self { divergence_threshold: 0.35 }
+    }
+}
+
+/// Inheritance, mutation and speciation settings for the garden
+/// Mutations are drawn from the seed, the parent and the day, so a seeded garden evolves the same way every time
+#[derive(Clone, Debug)]
+pub struct Genetics {
+    pub seed: u64,
+    /// Largest fractional change to each trait between parent and offspring
+    pub mutation_rate: f32,
+    /// Spin off variant species when set
+    pub speciation: Option<SpeciationRule>,
+    pub lineages: LineageTracker,
+    /// Genome each species started from; species not listed started from the default genome
+    founders: HashMap<&'static str, Genome>,
+    /// Every variant species founded so far
+    variants: HashMap<&'static str, Arc<Species>>,
+    /// Variant names handed out so far, shared by every copy of these settings so a day that is rolled back
+    /// and replayed reuses the names it founded instead of allocating them again
+    names: Arc<Mutex<HashMap<String, &'static str>>>,
+}
+
+impl Default for Genetics {
+    fn default() -> Self {
+        Self::new(0x6E_0E5E)
+    }
+}
+
+impl Genetics {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            mutation_rate: 0.05,
+            speciation: None,
+            lineages: LineageTracker::default(),
+            founders: HashMap::new(),
+            variants: HashMap::new(),
+            names: Arc::default(),
+        }
+    }
+
+    pub fn with_mutation_rate(mut self, rate: f32) -> Self {
+        self.mutation_rate = rate;
+        self
+    }
+
+    pub fn with_speciation(mut self, rule: SpeciationRule) -> Self {
+        self.speciation = Some(rule);
+        self
+    }
+
+    /// Genome of an offspring born to `parent_id` on `day`
+    pub fn offspring_genome(&self, parent: &Genome, parent_id: u64, day: u32) -> Genome {
+        let seed = self.seed ^ (day as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) ^ parent_id.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        parent.mutated(&mut fastrand::Rng::with_seed(seed), self.mutation_rate)
+    }
+
+    /// Genome a species started from
+    pub fn founder_of(&self, species: &str) -> Genome {
+        self.founders.get(species).copied().unwrap_or_default()
+    }
+
+    /// If speciation is enabled and `genome` has drifted far enough from its species' founders,
+    /// the variant species it belongs to: an existing variant whose founders it is close to, or else a newly founded one
+    /// The flag is true when the variant was founded by this call
+    pub fn diverged_variant(&mut self, species: &Species, genome: &Genome) -> Option<(Arc<Species>, bool)> {
+        let threshold = self.speciation.as_ref()?.divergence_threshold;
+        if genome.divergence(&self.founder_of(species.name)) < threshold {
+            return None;
+        }
+
+        let existing = self.lineages.variants_of(species.name)
+            .into_iter()
+            .find(|variant| genome.divergence(&self.founder_of(variant)) < threshold);
+        if let Some(variant) = existing.and_then(|name| self.variants.get(name)) {
+            return Some((variant.clone(), false));
+        }
+
+        // Variants are numbered within their original species, so names stay short however deep the lineage goes
+        let root = self.lineages.root_of(species.name);
+        let number = self.lineages.parents.keys().filter(|variant| self.lineages.root_of(variant) == root).count() + 1;
+        let name = self.intern(format!("{} Variant {}", root, number));
+
+        let mut variant = species.clone();
+        variant.name = name;
+        let variant = Arc::new(variant);
+        self.founders.insert(name, *genome);
+        self.variants.insert(name, variant.clone());
+        self.lineages.parents.insert(name, species.name);
+        Some((variant, true))
+    }
+
+    /// A 'static copy of a variant name; species names are 'static, so each distinct name is allocated once for good
+    fn intern(&self, name: String) -> &'static str {
+        let mut names = self.names.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(interned) = names.get(&name) {
+            return interned;
+        }
+        let interned: &'static str = Box::leak(name.clone().into_boxed_str());
+        names.insert(name, interned);
+        interned
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::lifecycle::EcosystemPopulation;
+    use crate::gameplay::species::get_species;
+
+    fn drifted() -> Genome {
+        Genome { size: 1.6, ..Genome::default() }
+    }
+
+    #[test]
+    fn replaying_a_day_reuses_the_variant_names_it_founded() {
+        let rabbit = get_species("Rabbit").unwrap();
+        let mut genetics = Genetics::default().with_speciation(SpeciationRule::default());
+        let snapshot = genetics.clone();
+
+        let (first, founded) = genetics.diverged_variant(rabbit, &drifted()).unwrap();
+        assert!(founded);
+        // Rolling back restores the snapshot, which then founds the same variant again
+        genetics = snapshot;
+        let (replayed, _) = genetics.diverged_variant(rabbit, &drifted()).unwrap();
+
+        assert_eq!(replayed.name, "Rabbit Variant 1");
+        assert!(std::ptr::eq(first.name, replayed.name));
+    }
+
+    #[test]
+    fn variants_count_against_their_original_species_capacity() {
+        let rabbit = get_species("Rabbit").unwrap();
+        let mut ecosystem = EcosystemPopulation::default();
+        ecosystem.genetics.speciation = Some(SpeciationRule::default());
+        let (variant, _) = ecosystem.genetics.diverged_variant(rabbit, &drifted()).unwrap();
+
+        ecosystem.add_mature_creature(rabbit.clone(), (0, 5));
+        ecosystem.add_mature_creature(variant.clone(), (0, 5));
+        ecosystem.add_mature_creature(variant.clone(), (0, 5));
+
+        assert_eq!(ecosystem.population_count(variant.name), 2);
+        assert_eq!(ecosystem.lineage_population(variant.name), 3);
+        assert_eq!(ecosystem.lineage_population("Rabbit"), 3);
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+use super::{EcosystemMatter, MatterType, DailyInputs};
+
+/// Default garden size in tiles
+pub const DEFAULT_GRID_WIDTH: u32 = 3;
+pub const DEFAULT_GRID_HEIGHT: u32 = 3;
+
+/// Matter that spreads between neighbouring tiles each day
+pub const DIFFUSING_MATTER: [MatterType; 4] = [
+    MatterType::SoilNutrients,
+    MatterType::GroundWater,
+    MatterType::DeadPlantMatter,
+    MatterType::DeadAnimalMatter,
+];
+
+/// Each day a tile passes this fraction (1/n) of the difference in a diffusing pool to each lower neighbour
+const DIFFUSION_DIVISOR: u32 = 8;
+
+/// A tile's column and row in the garden grid, with (0, 0) in the top-left corner
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TilePosition {
+    pub x: u32,
+    pub y: u32,
+}
+
+impl TilePosition {
+    pub const fn new(x: u32, y: u32) -> Self {
+        Self { x, y }
+    }
+}
+
+/// The garden divided into tiles, each with its own matter pools
+/// Sunlight reaches every tile alike; the garden's stocks and daily rain and air are shared out between the tiles,
+/// so the garden as a whole holds the same as a single well-mixed pool; nutrients, water and dead matter stay local and slowly diffuse
+#[derive(Clone, Debug)]
+pub struct GardenGrid {
+    width: u32,
+    height: u32,
+    tiles: Vec<EcosystemMatter>,
+}
+
+impl Default for GardenGrid {
+    fn default() -> Self {
+        Self::new(DEFAULT_GRID_WIDTH, DEFAULT_GRID_HEIGHT)
+    }
+}
+
+impl GardenGrid {
+    /// Create a grid whose tiles share out the default matter pools between them
+    pub fn new(width: u32, height: u32) -> Self {
+        let width = width.max(1);
+        let height = height.max(1);
+        let mut grid = Self {
+            width,
+            height,
+            tiles: vec![EcosystemMatter { matter_pools: HashMap::new() }; (width * height) as usize],
+        };
+
+        let defaults = EcosystemMatter::default();
+        for matter_type in MatterType::all() {
+            let amount = defaults.get_amount(matter_type);
+            match matter_type {
+                MatterType::Sunlight => grid.tiles.iter_mut().for_each(|tile| tile.set_amount(matter_type, amount)),
+                _ => grid.add_matter_evenly(matter_type, amount),
+            }
+        }
+        grid
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn tile_count(&self) -> usize {
+        self.tiles.len()
+    }
+
+    /// Check whether a position lies inside the garden
+    pub fn contains(&self, position: TilePosition) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    /// Index of a tile in row-major order
+    pub fn index_of(&self, position: TilePosition) -> usize {
+        (position.y * self.width + position.x) as usize
+    }
+
+    /// Position of the tile at a row-major index
+    pub fn position_of(&self, index: usize) -> TilePosition {
+        TilePosition::new(index as u32 % self.width, index as u32 / self.width)
+    }
+
+    /// The middle tile, where creatures are placed when no position is given
+    pub fn center(&self) -> TilePosition {
+        TilePosition::new(self.width / 2, self.height / 2)
+    }
+
+    /// Clamp a position into the garden
+    pub fn clamp(&self, position: TilePosition) -> TilePosition {
+        TilePosition::new(position.x.min(self.width - 1), position.y.min(self.height - 1))
+    }
+
+    /// Matter pools of a tile
+    pub fn tile(&self, position: TilePosition) -> &EcosystemMatter {
+        &self.tiles[self.index_of(self.clamp(position))]
+    }
+
+    /// Matter pools of a tile for modification
+    pub fn tile_mut(&mut self, position: TilePosition) -> &mut EcosystemMatter {
+        let index = self.index_of(self.clamp(position));
+        &mut self.tiles[index]
+    }
+
+    /// Every tile's matter pools in row-major order
+    pub fn tiles(&self) -> impl Iterator<Item = &EcosystemMatter> {
+        self.tiles.iter()
+    }
+
+    /// Every tile's matter pools in row-major order, for modification
+    pub fn tiles_mut(&mut self) -> impl Iterator<Item = &mut EcosystemMatter> {
+        self.tiles.iter_mut()
+    }
+
+    /// Tiles sharing an edge with a position
+    pub fn neighbours(&self, position: TilePosition) -> Vec<TilePosition> {
+        let TilePosition { x, y } = position;
+        let mut neighbours = Vec::with_capacity(4);
+        if y > 0 {
+            neighbours.push(TilePosition::new(x, y - 1));
+        }
+        if x > 0 {
+            neighbours.push(TilePosition::new(x - 1, y));
+        }
+        if x + 1 < self.width {
+            neighbours.push(TilePosition::new(x + 1, y));
+        }
+        if y + 1 < self.height {
+            neighbours.push(TilePosition::new(x, y + 1));
+        }
+        neighbours
+    }
+
+    /// Pick a tile at or next to `origin` for a newcomer, spreading successive choices around it
+    pub fn dispersal_tile(&self, origin: TilePosition, choice: u32) -> TilePosition {
+        let origin = self.clamp(origin);
+        let mut options = vec![origin];
+        options.extend(self.neighbours(origin));
+        options[choice as usize % options.len()]
+    }
+
+    /// Amount of a matter type summed over the whole garden
+    pub fn total_amount(&self, matter_type: MatterType) -> u32 {
+        self.tiles.iter().map(|tile| tile.get_amount(matter_type)).sum()
+    }
+
+    /// Matter pools summed over the whole garden
+    /// Sunlight is the same on every tile, so it is reported once rather than summed
+    pub fn total_matter(&self) -> EcosystemMatter {
+        let mut totals = EcosystemMatter { matter_pools: HashMap::new() };
+        for matter_type in MatterType::all() {
+            let amount = match matter_type {
+                MatterType::Sunlight => self.tiles[0].get_amount(matter_type),
+                _ => self.total_amount(matter_type),
+            };
+            totals.set_amount(matter_type, amount);
+        }
+        totals
+    }
+
+    /// A tile's share of an amount spread evenly over the garden (the remainder goes to the first tiles)
+    fn even_share(&self, amount: u32, index: usize) -> u32 {
+        let count = self.tiles.len() as u32;
+        amount / count + u32::from((index as u32) < amount % count)
+    }
+
+    /// Add matter spread evenly over every tile
+    pub fn add_matter_evenly(&mut self, matter_type: MatterType, amount: u32) {
+        for index in 0..self.tiles.len() {
+            let share = self.even_share(amount, index);
+            self.tiles[index].add_matter(matter_type, share);
+        }
+    }
+
+    /// Deliver a day's environmental inputs: every tile gets the day's sunlight, and an even share of the rest
+    pub fn apply_daily_inputs(&mut self, inputs: &DailyInputs) {
+        for index in 0..self.tiles.len() {
+            let share = DailyInputs {
+                sunlight: inputs.sunlight,
+                rain: self.even_share(inputs.rain, index),
+                evaporation: self.even_share(inputs.evaporation, index),
+                co2: self.even_share(inputs.co2, index),
+                o2: self.even_share(inputs.o2, index),
+            };
+            self.tiles[index].apply_daily_inputs(&share);
+        }
+    }
+
+    /// What the whole garden would hold of a tile's pool if every tile were like it
+    /// Species' environmental ranges are written for the whole garden, so tiles are judged at this scale;
+    /// sunlight already reaches each tile in full
+    pub fn garden_scale(&self, matter_type: MatterType, tile_amount: u32) -> u32 {
+        match matter_type {
+            MatterType::Sunlight => tile_amount,
+            _ => tile_amount.saturating_mul(self.tiles.len() as u32),
+        }
+    }
+
+    /// Let nutrients, water and dead matter flow from richer tiles into poorer neighbours
+    /// Flows are computed from the pools at the start of diffusion, so the result doesn't depend on tile order,
+    /// and every unit leaving one tile arrives at another
+    pub fn diffuse(&mut self) {
+        for matter_type in DIFFUSING_MATTER {
+            let before: Vec<u32> = self.tiles.iter().map(|tile| tile.get_amount(matter_type)).collect();
+            let mut after = before.clone();
+
+            for index in 0..self.tiles.len() {
+                let position = self.position_of(index);
+                // Each edge is visited once, from its left or upper tile
+                for neighbour in [TilePosition::new(position.x + 1, position.y), TilePosition::new(position.x, position.y + 1)] {
+                    if !self.contains(neighbour) {
+                        continue;
+                    }
+                    let other = self.index_of(neighbour);
+                    let (from, to) = if before[index] >= before[other] { (index, other) } else { (other, index) };
+                    let flow = (before[from] - before[to]) / DIFFUSION_DIVISOR;
+                    after[from] -= flow;
+                    after[to] += flow;
+                }
+            }
+
+            for (tile, amount) in self.tiles.iter_mut().zip(after) {
+                tile.set_amount(matter_type, amount);
+            }
+        }
+    }
+}
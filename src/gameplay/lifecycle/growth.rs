@@ -1,40 +1,86 @@
 #![allow(unused_variables)]
 
+use std::collections::HashMap;
 use super::{
     LifecyclePhase, PhaseResult, PhaseError, LifecycleEvent,
-    EcosystemPopulation, MaturityStage
+    EcosystemPopulation, IndividualCreature, MaturityStage
 };
+use super::cohort::merge_cohorts;
+use super::invariants::{self, INVARIANT_CHECKS_ENABLED};
 
-/// Growth phase - juvenile creatures mature into adults
-/// Only processes creatures that were successfully fed in the feeding phase
+/// Growth phase - creatures move through their species' life stages
+/// Juveniles mature once fed, seeds germinate once their tile suits them and adults age into senescence
 pub struct GrowthPhase;
 
 impl GrowthPhase {
     pub fn new() -> Self {
         Self
     }
+
+    /// Move a creature through every stage transition it meets today, returning the stages it entered
+    fn advance(creature: &mut IndividualCreature) -> Vec<MaturityStage> {
+        let mut entered = Vec::new();
+        // A stage graph can't be longer than its transition list, which also guards against cycles
+        for _ in 0..creature.species.life_stages.transitions.len() {
+            let Some(stage) = creature.next_stage() else {
+                break;
+            };
+            creature.maturity_stage = stage;
+            entered.push(stage);
+        }
+        entered
+    }
 }
 
 impl LifecyclePhase for GrowthPhase {
     fn execute(&self, ecosystem: &mut EcosystemPopulation) -> Result<PhaseResult, PhaseError> {
         let mut events = Vec::new();
         let mut creatures_processed = 0;
+        // Entries into stages other than adulthood, reported per species
+        let mut stages_reached: HashMap<(&'static str, MaturityStage), u32> = HashMap::new();
 
-        // Process all juvenile creatures
+        // Process all creatures whose stage can lead somewhere
         for creature in ecosystem.living_creatures_mut() {
-            if creature.maturity_stage == MaturityStage::Juvenile {
-                creatures_processed += 1;
+            if creature.species.life_stages.transitions_from(creature.maturity_stage).next().is_none() {
+                continue;
+            }
+            creatures_processed += 1;
 
-                // Check if creature can grow
-                if creature.fed_status.is_well_fed(0.7) {
-                    creature.maturity_stage = MaturityStage::Mature;
-                    
+            for stage in Self::advance(creature) {
+                if stage == MaturityStage::Mature {
                     events.push(LifecycleEvent::CreatureGrew {
                         creature_id: creature.id,
                     });
+                } else {
+                    *stages_reached.entry((creature.species.name, stage)).or_insert(0) += 1;
+                }
+            }
+        }
+
+        // Cohorts move through their stages together
+        for cohort in &mut ecosystem.cohorts {
+            if cohort.member.species.life_stages.transitions_from(cohort.member.maturity_stage).next().is_none() {
+                continue;
+            }
+            creatures_processed += cohort.count;
+
+            for stage in Self::advance(&mut cohort.member) {
+                if stage == MaturityStage::Mature {
+                    events.push(LifecycleEvent::CohortGrew {
+                        cohort_id: cohort.id(),
+                        species: cohort.member.species.name,
+                        count: cohort.count,
+                    });
+                } else {
+                    *stages_reached.entry((cohort.member.species.name, stage)).or_insert(0) += cohort.count;
                 }
             }
         }
+        merge_cohorts(&mut ecosystem.cohorts, &mut ecosystem.grid);
+
+        let mut stages_reached: Vec<_> = stages_reached.into_iter().collect();
+        stages_reached.sort_by_key(|((species, stage), _)| (*species, stage.name()));
+        events.extend(stages_reached.into_iter().map(|((species, stage), count)| LifecycleEvent::StageReached { species, stage, count }));
 
         Ok(PhaseResult {
             creatures_processed,
@@ -43,15 +89,26 @@ impl LifecyclePhase for GrowthPhase {
         })
     }
 
-    fn validate_preconditions(&self, _ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+    fn validate_preconditions(&self, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+        if !INVARIANT_CHECKS_ENABLED {
+            return Ok(());
+        }
+
         // Validate that feeding phase has been completed
         // (all creatures should have updated feeding status)
-        Ok(())
+        invariants::check_fed_status_fresh(self.phase_name(), ecosystem)?;
+        invariants::check_population_invariants(self.phase_name(), ecosystem)
     }
 
-    fn validate_postconditions(&self, _ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
-        // Validate that growth requirements were properly applied
-        Ok(())
+    fn validate_postconditions(&self, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+        if !INVARIANT_CHECKS_ENABLED {
+            return Ok(());
+        }
+
+        // Validate that every stage transition met today was taken
+        invariants::check_growth_age(self.phase_name(), ecosystem)?;
+        invariants::check_stage_transitions(self.phase_name(), ecosystem)?;
+        invariants::check_population_invariants(self.phase_name(), ecosystem)
     }
 
     fn phase_name(&self) -> &'static str {
@@ -64,3 +121,45 @@ impl Default for GrowthPhase {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::species::get_species;
+
+    #[test]
+    fn seeds_germinate_once_old_enough_on_a_suitable_tile() {
+        let mut ecosystem = EcosystemPopulation::default();
+        let clover = get_species("Clover").unwrap().clone().with_seed_stage(3);
+        let young = ecosystem.add_creature(clover.clone(), (1, 0));
+        let ready = ecosystem.add_creature(clover, (1, 0));
+        for (id, age_days) in [(young, 2), (ready, 3)] {
+            let seed = ecosystem.creatures.get_mut(id).unwrap();
+            seed.age_days = age_days;
+            seed.in_growth_environment = true;
+        }
+
+        let result = GrowthPhase::new().execute(&mut ecosystem).unwrap();
+
+        assert_eq!(ecosystem.creatures.get(young).unwrap().maturity_stage, MaturityStage::Seed);
+        assert_eq!(ecosystem.creatures.get(ready).unwrap().maturity_stage, MaturityStage::Juvenile);
+        assert!(result.events.iter().any(|event| matches!(
+            event,
+            LifecycleEvent::StageReached { species: "Clover", stage: MaturityStage::Juvenile, count: 1 }
+        )));
+    }
+
+    #[test]
+    fn adults_grow_senescent_and_stop_breeding() {
+        let mut ecosystem = EcosystemPopulation::default();
+        let rabbit = ecosystem.add_mature_creature(get_species("Rabbit").unwrap().clone().with_senescence(40), (0, 5));
+        ecosystem.creatures.get_mut(rabbit).unwrap().age_days = 40;
+
+        GrowthPhase::new().execute(&mut ecosystem).unwrap();
+
+        let rabbit = ecosystem.creatures.get(rabbit).unwrap();
+        assert_eq!(rabbit.maturity_stage, MaturityStage::Senescent);
+        assert!(rabbit.is_mature());
+        assert!(!rabbit.stage_traits().can_reproduce);
+    }
+}
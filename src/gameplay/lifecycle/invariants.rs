@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use super::{CreatureId, EcosystemPopulation, IndividualCreature, MaturityStage, PhaseError};
+use crate::gameplay::species::StageTrigger;
+
+/// Whether the full invariant suite runs between phases (enable with the `invariant_checks` feature)
+pub const INVARIANT_CHECKS_ENABLED: bool = cfg!(feature = "invariant_checks");
+
+/// Details of a broken simulation invariant
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvariantViolation {
+    /// Phase whose pre/postcondition caught the violation
+    pub phase: &'static str,
+    /// Short name of the broken invariant
+    pub invariant: &'static str,
+    /// The offending creature, if the violation is about a single creature
+    pub creature_id: Option<CreatureId>,
+    pub detail: String,
+}
+
+impl std::fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} phase broke '{}'", self.phase, self.invariant)?;
+        if let Some(creature_id) = self.creature_id {
+            write!(f, " (creature {})", creature_id)?;
+        }
+        write!(f, ": {}", self.detail)
+    }
+}
+
+fn violation(phase: &'static str, invariant: &'static str, creature_id: Option<CreatureId>, detail: String) -> PhaseError {
+    PhaseError::InvalidCreatureState(InvariantViolation {
+        phase,
+        invariant,
+        creature_id,
+        detail,
+    })
+}
+
+/// No dead creatures may remain in the population
+pub fn check_no_dead_creatures(phase: &'static str, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+    if let Some(creature) = ecosystem.creatures.iter().find(|c| c.maturity_stage == MaturityStage::Dead) {
+        return Err(violation(
+            phase,
+            "no dead creatures",
+            Some(creature.id),
+            format!("dead {} still in population", creature.species.name),
+        ));
+    }
+
+    Ok(())
+}
+
+/// A dead creature reached removal without a recorded cause of death
+pub fn missing_death_cause(phase: &'static str, creature: &IndividualCreature) -> PhaseError {
+    violation(
+        phase,
+        "recorded death cause",
+        Some(creature.id),
+        format!("dead {} has no cause of death", creature.species.name),
+    )
+}
+
+/// Every living creature that feeds today must have been fed (or found starving) during the current day
+pub fn check_fed_status_fresh(phase: &'static str, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+    for creature in ecosystem.living_creatures().filter(|c| c.feeds()) {
+        if creature.fed_on_day != Some(ecosystem.current_day) {
+            return Err(violation(
+                phase,
+                "fresh feeding status",
+                Some(creature.id),
+                format!(
+                    "{} last fed on {:?}, expected day {}",
+                    creature.species.name, creature.fed_on_day, ecosystem.current_day
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Cached living counts must match a recount of the creatures and cohort members
+pub fn check_population_counts(phase: &'static str, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+    let mut recount: HashMap<&str, u32> = HashMap::new();
+    for creature in ecosystem.living_creatures() {
+        *recount.entry(creature.species.name).or_insert(0) += 1;
+    }
+    for cohort in &ecosystem.cohorts {
+        *recount.entry(cohort.member.species.name).or_insert(0) += cohort.count;
+    }
+
+    for (species_name, cached) in &ecosystem.living_population_by_species {
+        let actual = recount.get(species_name.as_str()).copied().unwrap_or(0);
+        if *cached != actual {
+            return Err(violation(
+                phase,
+                "population counts",
+                None,
+                format!("{} cached as {} but {} are alive", species_name, cached, actual),
+            ));
+        }
+    }
+
+    for (species_name, actual) in recount {
+        if !ecosystem.living_population_by_species.contains_key(species_name) {
+            return Err(violation(
+                phase,
+                "population counts",
+                None,
+                format!("{} has {} alive but no cached count", species_name, actual),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// No creature may be left in a stage it has met a transition out of
+pub fn check_stage_transitions(phase: &'static str, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+    for creature in ecosystem.living_creatures() {
+        if let Some(next) = creature.next_stage() {
+            return Err(violation(
+                phase,
+                "stage transitions",
+                Some(creature.id),
+                format!(
+                    "{} aged {} days is still {} but is ready to become {}",
+                    creature.species.name, creature.age_days, creature.maturity_stage.name(), next.name()
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// No juvenile may stay juvenile past its growth age while well fed and able to grow
+pub fn check_growth_age(phase: &'static str, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+    let members = ecosystem.living_creatures().chain(ecosystem.cohorts.iter().map(|cohort| &cohort.member));
+    for creature in members.filter(|c| c.maturity_stage == MaturityStage::Juvenile) {
+        let well_fed = creature.species.life_stages
+            .transitions_from(MaturityStage::Juvenile)
+            .any(|transition| match transition.trigger {
+                StageTrigger::WellFed { threshold } => creature.fed_status.is_well_fed(threshold),
+                _ => false,
+            });
+        let growth_age = creature.species.growth_requirements.minimum_age;
+
+        if well_fed && creature.age_days > growth_age && !creature.dormant && creature.in_growth_environment {
+            return Err(violation(
+                phase,
+                "growth age",
+                Some(creature.id),
+                format!(
+                    "well-fed {} aged {} days is still juvenile past its growth age of {}",
+                    creature.species.name, creature.age_days, growth_age
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Creature ids only ever increase: the next id must be beyond every id handed out so far
+pub fn check_creature_ids(phase: &'static str, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+    let mut members = ecosystem.creatures.iter().chain(ecosystem.cohorts.iter().map(|cohort| &cohort.member));
+    if let Some(creature) = members.find(|c| c.id >= ecosystem.next_creature_id) {
+        return Err(violation(
+            phase,
+            "monotonic creature ids",
+            Some(creature.id),
+            format!("id is not below next_creature_id {}", ecosystem.next_creature_id),
+        ));
+    }
+
+    Ok(())
+}
+
+/// The id counter never goes backwards: after a phase it must be at least where it was before
+pub fn check_id_counter_advanced(phase: &'static str, before: CreatureId, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+    if ecosystem.next_creature_id < before {
+        return Err(violation(
+            phase,
+            "monotonic creature ids",
+            None,
+            format!("next_creature_id went back from {} to {}", before, ecosystem.next_creature_id),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Invariants that hold between every pair of phases
+pub fn check_population_invariants(phase: &'static str, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+    if !INVARIANT_CHECKS_ENABLED {
+        return Ok(());
+    }
+
+    check_population_counts(phase, ecosystem)?;
+    check_creature_ids(phase, ecosystem)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::lifecycle::FeedingResult;
+    use crate::gameplay::species::get_species;
+
+    /// A well-fed juvenile rabbit in a garden it can grow in
+    fn well_fed_juvenile(age_days: u32) -> (EcosystemPopulation, CreatureId) {
+        let rabbit = get_species("Rabbit").unwrap().clone();
+        let mut ecosystem = EcosystemPopulation::default();
+        let creature_id = ecosystem.add_creature(rabbit, (3, 2));
+        let creature = ecosystem.creatures.get_mut(creature_id).unwrap();
+        creature.age_days = age_days;
+        creature.in_growth_environment = true;
+        creature.set_fed_status(FeedingResult::FullyFed);
+        (ecosystem, creature_id)
+    }
+
+    #[test]
+    fn juveniles_mature_once_they_reach_their_growth_age() {
+        let growth_age = get_species("Rabbit").unwrap().growth_requirements.minimum_age;
+
+        let (ecosystem, young) = well_fed_juvenile(growth_age - 1);
+        assert_eq!(ecosystem.creatures.get(young).unwrap().next_stage(), None);
+
+        let (ecosystem, grown) = well_fed_juvenile(growth_age);
+        assert_eq!(ecosystem.creatures.get(grown).unwrap().next_stage(), Some(MaturityStage::Mature));
+    }
+
+    #[test]
+    fn well_fed_juvenile_past_its_growth_age_is_a_violation() {
+        let growth_age = get_species("Rabbit").unwrap().growth_requirements.minimum_age;
+
+        let (ecosystem, _) = well_fed_juvenile(growth_age);
+        assert!(check_growth_age("Growth", &ecosystem).is_ok());
+
+        let (ecosystem, stuck) = well_fed_juvenile(growth_age + 1);
+        match check_growth_age("Growth", &ecosystem) {
+            Err(PhaseError::InvalidCreatureState(violation)) => {
+                assert_eq!(violation.invariant, "growth age");
+                assert_eq!(violation.creature_id, Some(stuck));
+            }
+            other => panic!("expected a growth age violation, got {:?}", other),
+        }
+    }
+}
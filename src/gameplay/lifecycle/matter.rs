@@ -1,5 +1,5 @@
-use bevy::prelude::*;
 use std::collections::HashMap;
+use super::climate::DailyInputs;
 
 /// Extended matter types for the ecosystem
 /// Includes both living biomass and environmental resources
@@ -13,6 +13,9 @@ pub enum MatterType {
     DeadPlantMatter,
     DeadAnimalMatter,
     
+    // Reproductive plant matter (fruit and seeds) lying on tiles until eaten, sown or rotted
+    Fruit,
+    
     // Environmental resources
     SoilNutrients,
     Sunlight,
@@ -28,6 +31,7 @@ impl MatterType {
             MatterType::AnimalMatter => "Animal Matter",
             MatterType::DeadPlantMatter => "Dead Plant Matter",
             MatterType::DeadAnimalMatter => "Dead Animal Matter",
+            MatterType::Fruit => "Fruit",
             MatterType::SoilNutrients => "Soil Nutrients",
             MatterType::Sunlight => "Sunlight",
             MatterType::GroundWater => "Ground Water",
@@ -42,6 +46,7 @@ impl MatterType {
             MatterType::AnimalMatter,
             MatterType::DeadPlantMatter,
             MatterType::DeadAnimalMatter,
+            MatterType::Fruit,
             MatterType::SoilNutrients,
             MatterType::Sunlight,
             MatterType::GroundWater,
@@ -60,6 +65,11 @@ impl MatterType {
         matches!(self, MatterType::DeadPlantMatter | MatterType::DeadAnimalMatter)
     }
 
+    /// Check if this matter type is fruit or seeds set by plants
+    pub fn is_reproductive_matter(&self) -> bool {
+        matches!(self, MatterType::Fruit)
+    }
+
     /// Check if this is an environmental resource
     pub fn is_environmental_resource(&self) -> bool {
         matches!(self, 
@@ -95,7 +105,8 @@ impl std::error::Error for MatterConservationError {}
 
 /// Tracks all matter pools in the ecosystem
 /// This includes environmental resources and dead matter available for consumption
-#[derive(Clone, Debug, Resource)]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy", derive(bevy::prelude::Resource))]
 pub struct EcosystemMatter {
     pub matter_pools: HashMap<MatterType, u32>,
 }
@@ -111,9 +122,10 @@ impl Default for EcosystemMatter {
         matter_pools.insert(MatterType::CO2, 50);
         matter_pools.insert(MatterType::O2, 50);
         
-        // No dead matter initially
+        // No dead matter or fruit initially
         matter_pools.insert(MatterType::DeadPlantMatter, 0);
         matter_pools.insert(MatterType::DeadAnimalMatter, 0);
+        matter_pools.insert(MatterType::Fruit, 0);
         
         // Living biomass is tracked in creatures, not here
         matter_pools.insert(MatterType::PlantMatter, 0);
@@ -153,20 +165,21 @@ impl EcosystemMatter {
         self.get_amount(matter_type) >= amount
     }
 
-    /// Apply daily environmental inputs
-    pub fn apply_daily_inputs(&mut self) {
+    /// Apply a day's environmental inputs from the season and weather
+    pub fn apply_daily_inputs(&mut self, inputs: &DailyInputs) {
         // Renewable resources get daily replenishment
-        self.set_amount(MatterType::Sunlight, 100);  // Full daily sunlight
-        self.add_matter(MatterType::GroundWater, 10); // Daily precipitation
-        self.add_matter(MatterType::CO2, 5);          // Atmospheric exchange
-        self.add_matter(MatterType::O2, 5);           // Atmospheric exchange
+        self.set_amount(MatterType::Sunlight, inputs.sunlight);     // Sunlight doesn't carry over
+        self.add_matter(MatterType::GroundWater, inputs.rain);      // Daily precipitation
+        self.consume_matter(MatterType::GroundWater, inputs.evaporation);
+        self.add_matter(MatterType::CO2, inputs.co2);               // Atmospheric exchange
+        self.add_matter(MatterType::O2, inputs.o2);                 // Atmospheric exchange
     }
 
-    /// Calculate total environmental matter (excluding living biomass)
+    /// Calculate total environmental matter (excluding living biomass, but including fruit waiting on the ground)
     pub fn total_environmental_matter(&self) -> u32 {
         MatterType::all()
             .iter()
-            .filter(|mt| mt.is_environmental_resource() || mt.is_dead_matter())
+            .filter(|mt| mt.is_environmental_resource() || mt.is_dead_matter() || mt.is_reproductive_matter())
             .map(|mt| self.get_amount(*mt))
             .sum()
     }
@@ -1,27 +1,71 @@
 pub mod creature;
 pub mod matter;
+pub mod climate;
+pub mod grid;
+pub mod genetics;
+pub mod capacity;
+pub mod store;
+pub mod cohort;
 pub mod population;
 pub mod phases;
 pub mod feeding;
+pub mod feeding_policy;
 pub mod growth;
 pub mod reproduction;
 pub mod death;
+pub mod environment;
+pub mod ecological_events;
+pub mod disease;
+pub mod symbiosis;
+pub mod decay;
+pub mod invariants;
+#[cfg(feature = "bevy")]
+pub mod events;
 
 // Re-export key types for easier access
-pub use creature::{IndividualCreature, CreatureBiomass, MaturityStage, FeedingResult, CreatureId, DeathCause};
+pub use creature::{IndividualCreature, CreatureBiomass, MaturityStage, FeedingResult, CreatureId, DeathCause, HealthStatus};
+pub use store::{CreatureStore, trophic_level};
+pub use cohort::{Cohort, CohortKey, COHORT_AGE_BUCKET_DAYS, COHORT_GENOME_BUCKET_WIDTH, DEFAULT_COHORT_THRESHOLD};
 pub use matter::{EcosystemMatter, MatterType, MatterConservationError};
-pub use population::{EcosystemPopulation, handle_add_species_to_ecosystem_event, handle_simulate_day_event};
-pub use phases::{LifecyclePhase, DailySimulation, PhaseResult, PhaseError, LifecycleEvent};
+pub use climate::{Climate, Season, Weather, DailyInputs, DAYS_PER_SEASON};
+pub use genetics::{Genome, Genetics, LineageTracker, TraitSample, SpeciationRule, TRAIT_RANGE, scale_trait};
+pub use grid::{GardenGrid, TilePosition, DEFAULT_GRID_WIDTH, DEFAULT_GRID_HEIGHT, DIFFUSING_MATTER};
+pub use capacity::{CarryingCapacity, CapacityScaling, DensityPenalty};
+pub use population::{EcosystemPopulation, MortalityStats};
+#[cfg(feature = "bevy")]
+pub use population::{handle_add_species_to_ecosystem_event, handle_simulate_day_event};
+pub use phases::{LifecyclePhase, DailySimulation, DailySimulationBuilder, DailyResult, DailySummary, PhaseResult, PhaseError, LifecycleEvent};
 pub use feeding::FeedingPhaseImpl;
+pub use ecological_events::{EcologicalEventKind, EcologicalEventOdds, EcologicalEvents, EcologicalEventPhase};
+pub use symbiosis::{Symbiosis, SymbiosisMap, SymbiosisPhase};
+pub use disease::{Disease, DiseasePhase};
+pub use decay::{Decay, DecayPhase};
+#[cfg(feature = "bevy")]
+pub use events::{
+    CreatureBornEvent, CreatureDiedEvent, CreatureMaturedEvent, CreatureFedEvent, MatterTransformedEvent,
+    EcologicalEventOccurred, DayCompletedEvent, LifecycleEventWriters,
+};
+pub use invariants::{InvariantViolation, INVARIANT_CHECKS_ENABLED};
+pub use feeding_policy::{
+    FeedingAllocationPolicy, FeedingPolicyKind, FeedingRequest, AllocationContext,
+    FirstComePolicy, ProportionalSharePolicy, JuvenilesFirstPolicy, MatureFirstPolicy, RandomLotteryPolicy,
+};
 
 // Events for game integration
-use bevy::prelude::*;
-
-#[derive(Event)]
+#[cfg_attr(feature = "bevy", derive(bevy::prelude::Event))]
 pub struct SimulateDayEvent;
 
-#[derive(Event)]
+/// Sent when a day fails to simulate and the garden is rolled back to the start of that day
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy", derive(bevy::prelude::Event))]
+pub struct SimulationFailedEvent {
+    pub day: u32,
+    pub message: String,
+}
+
+#[cfg_attr(feature = "bevy", derive(bevy::prelude::Event))]
 pub struct AddSpeciesToEcosystemEvent {
     pub species: crate::gameplay::species::Species,
     pub starting_biomass: (u32, u32), // (plant_matter, animal_matter)
+    pub location: TilePosition, // Garden tile the player placed the species on
 }
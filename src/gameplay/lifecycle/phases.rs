@@ -1,9 +1,9 @@
-use bevy::prelude::*;
 use super::{EcosystemPopulation, MatterConservationError};
+use super::invariants::{self, INVARIANT_CHECKS_ENABLED};
 
 /// Trait for individual lifecycle phases
 /// Each phase processes all relevant creatures before moving to the next phase
-pub trait LifecyclePhase {
+pub trait LifecyclePhase: Send + Sync {
     /// Execute this phase on the ecosystem
     fn execute(&self, ecosystem: &mut EcosystemPopulation) -> Result<PhaseResult, PhaseError>;
     
@@ -32,16 +32,36 @@ pub enum LifecycleEvent {
     CreatureGrew { creature_id: super::CreatureId },
     CreatureReproduced { parent_id: super::CreatureId, offspring_id: super::CreatureId },
     CreatureDied { creature_id: super::CreatureId, cause: super::DeathCause },
+    // Cohort mode counterparts, covering `count` creatures at once
+    CohortFed { cohort_id: super::CreatureId, species: &'static str, count: u32, satisfaction: f32 },
+    CohortGrew { cohort_id: super::CreatureId, species: &'static str, count: u32 },
+    CohortReproduced { parent_cohort_id: super::CreatureId, offspring_cohort_id: super::CreatureId, species: &'static str, offspring: u32 },
+    CohortDied { cohort_id: super::CreatureId, species: &'static str, cause: super::DeathCause, count: u32 },
     MatterTransformed { from_type: super::MatterType, to_type: super::MatterType, amount: u32 },
+    // A random event struck the garden; its effects follow as separate events
+    EcologicalEvent { kind: super::EcologicalEventKind, description: String },
+    // A lineage drifted far enough from its founders to become a new species; `creature_id` is its first member
+    VariantEmerged { creature_id: super::CreatureId, parent_species: &'static str, species: &'static str },
+    // A parasite took biomass from a nearby host
+    Parasitized { parasite_id: super::CreatureId, host_id: super::CreatureId, amount: u32 },
+    // An infection appeared among a species crowded onto a tile
+    DiseaseOutbreak { species: &'static str, tile: super::TilePosition },
+    // Creatures of a species that caught, or recovered from, the disease today
+    Infected { species: &'static str, count: u32 },
+    Recovered { species: &'static str, count: u32 },
+    // Creatures of a species that entered a life stage other than adulthood today (adults are reported as grown)
+    StageReached { species: &'static str, stage: super::MaturityStage, count: u32 },
 }
 
 /// Errors that can occur during lifecycle phases
 #[derive(Debug)]
 pub enum PhaseError {
     MatterConservationViolation(MatterConservationError),
-    InvalidCreatureState(String),
+    InvalidCreatureState(super::InvariantViolation),
     InsufficientResources(String),
     SystemError(String),
+    /// Strict mode stopped the simulation on a phase failure, leaving the broken state for inspection
+    Halted { day: u32, phase: &'static str, error: Box<PhaseError> },
 }
 
 impl From<MatterConservationError> for PhaseError {
@@ -54,9 +74,10 @@ impl std::fmt::Display for PhaseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             PhaseError::MatterConservationViolation(e) => write!(f, "Matter conservation error: {}", e),
-            PhaseError::InvalidCreatureState(msg) => write!(f, "Invalid creature state: {}", msg),
+            PhaseError::InvalidCreatureState(violation) => write!(f, "Invalid creature state: {}", violation),
             PhaseError::InsufficientResources(msg) => write!(f, "Insufficient resources: {}", msg),
             PhaseError::SystemError(msg) => write!(f, "System error: {}", msg),
+            PhaseError::Halted { day, phase, error } => write!(f, "Day {} halted in {} phase: {}", day, phase, error),
         }
     }
 }
@@ -65,8 +86,12 @@ impl std::error::Error for PhaseError {}
 
 /// Orchestrates the complete daily simulation cycle
 /// Ensures phases are executed in the correct order with proper validation
+/// Registered as a resource so plugins can add, remove or reorder phases by name
+#[cfg_attr(feature = "bevy", derive(bevy::prelude::Resource))]
 pub struct DailySimulation {
-    pub phases: Vec<Box<dyn LifecyclePhase>>,
+    phases: Vec<Box<dyn LifecyclePhase>>,
+    /// Debug mode: halt on a phase failure instead of rolling the day back
+    pub strict_mode: bool,
 }
 
 impl DailySimulation {
@@ -74,36 +99,160 @@ impl DailySimulation {
     pub fn new() -> Self {
         Self {
             phases: vec![
+                Box::new(super::ecological_events::EcologicalEventPhase::new()),
+                Box::new(super::disease::DiseasePhase::new()),
                 Box::new(super::death::DeathPhase::new()),
+                Box::new(super::environment::EnvironmentPhase::new()),
                 Box::new(super::feeding::FeedingPhaseImpl::new()),
+                Box::new(super::symbiosis::SymbiosisPhase::new()),
                 Box::new(super::growth::GrowthPhase::new()),
                 Box::new(super::reproduction::ReproductionPhase::new()),
+                Box::new(super::decay::DecayPhase::new()),
             ],
+            strict_mode: false,
         }
     }
 
+    /// Create a daily simulation with no phases
+    pub fn empty() -> Self {
+        Self {
+            phases: Vec::new(),
+            strict_mode: false,
+        }
+    }
+
+    /// Start building a simulation from the default phases
+    pub fn builder() -> DailySimulationBuilder {
+        DailySimulationBuilder::new(Self::new())
+    }
+
+    /// Names of the registered phases in execution order
+    pub fn phase_names(&self) -> Vec<&'static str> {
+        self.phases.iter().map(|phase| phase.phase_name()).collect()
+    }
+
+    /// Check whether a phase with this name is registered
+    pub fn has_phase(&self, name: &str) -> bool {
+        self.phase_index(name).is_some()
+    }
+
+    /// Append a phase to the end of the day
+    pub fn add_phase(&mut self, phase: Box<dyn LifecyclePhase>) -> Result<(), PhaseError> {
+        self.ensure_unique(phase.as_ref())?;
+        self.phases.push(phase);
+        Ok(())
+    }
+
+    /// Insert a phase immediately before the named phase
+    pub fn add_phase_before(&mut self, anchor: &str, phase: Box<dyn LifecyclePhase>) -> Result<(), PhaseError> {
+        self.ensure_unique(phase.as_ref())?;
+        let index = self.require_phase(anchor)?;
+        self.phases.insert(index, phase);
+        Ok(())
+    }
+
+    /// Insert a phase immediately after the named phase
+    pub fn add_phase_after(&mut self, anchor: &str, phase: Box<dyn LifecyclePhase>) -> Result<(), PhaseError> {
+        self.ensure_unique(phase.as_ref())?;
+        let index = self.require_phase(anchor)?;
+        self.phases.insert(index + 1, phase);
+        Ok(())
+    }
+
+    /// Remove the named phase, returning it
+    pub fn remove_phase(&mut self, name: &str) -> Result<Box<dyn LifecyclePhase>, PhaseError> {
+        let index = self.require_phase(name)?;
+        Ok(self.phases.remove(index))
+    }
+
+    /// Move the named phase so it runs immediately before another
+    pub fn move_phase_before(&mut self, name: &str, anchor: &str) -> Result<(), PhaseError> {
+        self.require_phase(anchor)?;
+        if name == anchor {
+            return Ok(());
+        }
+        let phase = self.remove_phase(name)?;
+        let index = self.require_phase(anchor)?;
+        self.phases.insert(index, phase);
+        Ok(())
+    }
+
+    /// Move the named phase so it runs immediately after another
+    pub fn move_phase_after(&mut self, name: &str, anchor: &str) -> Result<(), PhaseError> {
+        self.require_phase(anchor)?;
+        if name == anchor {
+            return Ok(());
+        }
+        let phase = self.remove_phase(name)?;
+        let index = self.require_phase(anchor)?;
+        self.phases.insert(index + 1, phase);
+        Ok(())
+    }
+
+    fn phase_index(&self, name: &str) -> Option<usize> {
+        self.phases.iter().position(|phase| phase.phase_name() == name)
+    }
+
+    fn require_phase(&self, name: &str) -> Result<usize, PhaseError> {
+        self.phase_index(name)
+            .ok_or_else(|| PhaseError::SystemError(format!("No lifecycle phase named '{}'", name)))
+    }
+
+    fn ensure_unique(&self, phase: &dyn LifecyclePhase) -> Result<(), PhaseError> {
+        if self.has_phase(phase.phase_name()) {
+            return Err(PhaseError::SystemError(format!(
+                "Lifecycle phase '{}' is already registered", phase.phase_name()
+            )));
+        }
+        Ok(())
+    }
+
     /// Execute one complete daily cycle
+    /// The day is transactional: if any phase fails, the ecosystem is restored to its state before the day
+    /// In strict mode a failure is reported as `PhaseError::Halted` instead, leaving the broken state for inspection
     pub fn simulate_day(&self, ecosystem: &mut EcosystemPopulation) -> Result<DailyResult, PhaseError> {
+        let snapshot = ecosystem.clone();
+
+        match self.run_phases(ecosystem) {
+            Ok(result) => Ok(result),
+            Err((phase, error)) => {
+                if self.strict_mode {
+                    return Err(PhaseError::Halted { day: snapshot.current_day, phase, error: Box::new(error) });
+                }
+
+                *ecosystem = snapshot;
+                Err(error)
+            }
+        }
+    }
+
+    /// Run every phase in order, reporting which phase failed
+    fn run_phases(&self, ecosystem: &mut EcosystemPopulation) -> Result<DailyResult, (&'static str, PhaseError)> {
         let mut total_events = Vec::new();
         let mut phase_results = Vec::new();
 
-        println!("Starting daily simulation for day {}", ecosystem.current_day + 1);
+        ecosystem.begin_day();
+        ecosystem.update_population_mode();
 
         // Execute each phase in order
         for phase in &self.phases {
+            let phase_name = phase.phase_name();
+
             // Validate preconditions
-            phase.validate_preconditions(ecosystem)?;
+            phase.validate_preconditions(ecosystem).map_err(|e| (phase_name, e))?;
 
             // Execute phase
-            let result = phase.execute(ecosystem)?;
-            println!("Completed {} phase: {} creatures processed", 
-                  phase.phase_name(), result.creatures_processed);
+            let next_id_before = ecosystem.next_creature_id;
+            let result = phase.execute(ecosystem).map_err(|e| (phase_name, e))?;
 
             // Validate postconditions
-            phase.validate_postconditions(ecosystem)?;
+            phase.validate_postconditions(ecosystem).map_err(|e| (phase_name, e))?;
+            if INVARIANT_CHECKS_ENABLED {
+                invariants::check_id_counter_advanced(phase_name, next_id_before, ecosystem).map_err(|e| (phase_name, e))?;
+            }
 
             total_events.extend(result.events.clone());
-            phase_results.push((phase.phase_name().to_string(), result));
+            phase_results.push((phase_name.to_string(), result));
         }
 
         // Advance to next day
@@ -124,6 +273,66 @@ impl Default for DailySimulation {
     }
 }
 
+/// Chainable construction of a customized daily simulation
+/// The first failed operation is reported by `build`
+pub struct DailySimulationBuilder {
+    simulation: DailySimulation,
+    error: Option<PhaseError>,
+}
+
+impl DailySimulationBuilder {
+    /// Start from an existing simulation
+    pub fn new(simulation: DailySimulation) -> Self {
+        Self { simulation, error: None }
+    }
+
+    pub fn with_phase(self, phase: Box<dyn LifecyclePhase>) -> Self {
+        self.apply(|simulation| simulation.add_phase(phase))
+    }
+
+    pub fn with_phase_before(self, anchor: &str, phase: Box<dyn LifecyclePhase>) -> Self {
+        self.apply(|simulation| simulation.add_phase_before(anchor, phase))
+    }
+
+    pub fn with_phase_after(self, anchor: &str, phase: Box<dyn LifecyclePhase>) -> Self {
+        self.apply(|simulation| simulation.add_phase_after(anchor, phase))
+    }
+
+    pub fn without_phase(self, name: &str) -> Self {
+        self.apply(|simulation| simulation.remove_phase(name).map(|_| ()))
+    }
+
+    pub fn with_phase_moved_before(self, name: &str, anchor: &str) -> Self {
+        self.apply(|simulation| simulation.move_phase_before(name, anchor))
+    }
+
+    pub fn with_phase_moved_after(self, name: &str, anchor: &str) -> Self {
+        self.apply(|simulation| simulation.move_phase_after(name, anchor))
+    }
+
+    pub fn with_strict_mode(mut self, strict: bool) -> Self {
+        self.simulation.strict_mode = strict;
+        self
+    }
+
+    /// Finish building, failing if any step referenced a missing or duplicate phase
+    pub fn build(self) -> Result<DailySimulation, PhaseError> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.simulation),
+        }
+    }
+
+    fn apply(mut self, operation: impl FnOnce(&mut DailySimulation) -> Result<(), PhaseError>) -> Self {
+        if self.error.is_none() {
+            if let Err(error) = operation(&mut self.simulation) {
+                self.error = Some(error);
+            }
+        }
+        self
+    }
+}
+
 /// Result of a complete daily simulation
 #[derive(Debug)]
 pub struct DailyResult {
@@ -134,6 +343,14 @@ pub struct DailyResult {
 }
 
 impl DailyResult {
+    /// Get the result reported by a specific phase
+    pub fn phase_result(&self, phase_name: &str) -> Option<&PhaseResult> {
+        self.phase_results
+            .iter()
+            .find(|(name, _)| name == phase_name)
+            .map(|(_, result)| result)
+    }
+
     /// Get summary statistics for the day
     pub fn get_summary(&self) -> DailySummary {
         let mut births = 0;
@@ -147,6 +364,10 @@ impl DailyResult {
                 LifecycleEvent::CreatureDied { .. } => deaths += 1,
                 LifecycleEvent::CreatureGrew { .. } => growth_events += 1,
                 LifecycleEvent::CreatureFed { .. } => feeding_events += 1,
+                LifecycleEvent::CohortReproduced { offspring, .. } => births += offspring,
+                LifecycleEvent::CohortDied { count, .. } => deaths += count,
+                LifecycleEvent::CohortGrew { count, .. } => growth_events += count,
+                LifecycleEvent::CohortFed { count, .. } => feeding_events += count,
                 _ => {}
             }
         }
@@ -163,7 +384,7 @@ impl DailyResult {
 }
 
 /// Summary statistics for a day's simulation
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct DailySummary {
     pub day: u32,
     pub births: u32,
@@ -172,3 +393,60 @@ pub struct DailySummary {
     pub feeding_events: u32,
     pub final_population: u32,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::species::get_species;
+
+    /// Plants a rabbit and then fails, so a rolled-back day loses the rabbit and a halted one keeps it
+    struct BrokenPhase;
+
+    impl LifecyclePhase for BrokenPhase {
+        fn execute(&self, ecosystem: &mut EcosystemPopulation) -> Result<PhaseResult, PhaseError> {
+            ecosystem.add_creature(get_species("Rabbit").unwrap().clone(), (0, 5));
+            Err(PhaseError::SystemError("broken".to_string()))
+        }
+
+        fn validate_preconditions(&self, _ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+            Ok(())
+        }
+
+        fn validate_postconditions(&self, _ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+            Ok(())
+        }
+
+        fn phase_name(&self) -> &'static str {
+            "Broken"
+        }
+    }
+
+    fn broken_simulation(strict: bool) -> DailySimulation {
+        DailySimulation::builder()
+            .with_phase_after("Ecological Events", Box::new(BrokenPhase))
+            .with_strict_mode(strict)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn a_failed_day_is_rolled_back() {
+        let mut ecosystem = EcosystemPopulation::default();
+
+        let error = broken_simulation(false).simulate_day(&mut ecosystem).unwrap_err();
+
+        assert!(matches!(error, PhaseError::SystemError(_)));
+        assert_eq!(ecosystem.population_count("Rabbit"), 0);
+        assert_eq!(ecosystem.current_day, 0);
+    }
+
+    #[test]
+    fn strict_mode_halts_and_keeps_the_broken_state() {
+        let mut ecosystem = EcosystemPopulation::default();
+
+        let error = broken_simulation(true).simulate_day(&mut ecosystem).unwrap_err();
+
+        assert!(matches!(error, PhaseError::Halted { day: 0, phase: "Broken", .. }));
+        assert_eq!(ecosystem.population_count("Rabbit"), 1);
+    }
+}
@@ -1,13 +1,63 @@
-use bevy::prelude::*;
 use std::collections::HashMap;
-use super::{IndividualCreature, CreatureId, DeathCause, EcosystemMatter, MatterType};
+use std::sync::Arc;
+use super::{IndividualCreature, CreatureId, CreatureStore, Cohort, DeathCause, GardenGrid, TilePosition, MatterType, CarryingCapacity, FeedingPolicyKind, Climate, Season, Weather, EcologicalEvents, Genetics, Genome, TraitSample, Symbiosis, Disease, Decay, PhaseError};
+use super::invariants;
 use crate::gameplay::species::Species;
 
+#[cfg(feature = "bevy")]
+use bevy::prelude::*;
+#[cfg(feature = "bevy")]
+use crate::gameplay::event_log::{EcosystemLog, LogEventKind};
+
+/// Cumulative death counts for a species, broken down by cause
+#[derive(Clone, Debug, Default)]
+pub struct MortalityStats {
+    pub starvation: u32,
+    pub natural_age: u32,
+    pub environmental_stress: u32,
+    pub predation: u32,
+    pub disaster: u32,
+    pub disease: u32,
+}
+
+impl MortalityStats {
+    /// Count one death of the given cause
+    pub fn record(&mut self, cause: &DeathCause) {
+        match cause {
+            DeathCause::Starvation => self.starvation += 1,
+            DeathCause::NaturalAge => self.natural_age += 1,
+            DeathCause::EnvironmentalStress => self.environmental_stress += 1,
+            DeathCause::Predation => self.predation += 1,
+            DeathCause::Disaster => self.disaster += 1,
+            DeathCause::Disease => self.disease += 1,
+        }
+    }
+
+    /// Number of deaths from a specific cause
+    pub fn count(&self, cause: &DeathCause) -> u32 {
+        match cause {
+            DeathCause::Starvation => self.starvation,
+            DeathCause::NaturalAge => self.natural_age,
+            DeathCause::EnvironmentalStress => self.environmental_stress,
+            DeathCause::Predation => self.predation,
+            DeathCause::Disaster => self.disaster,
+            DeathCause::Disease => self.disease,
+        }
+    }
+
+    /// Total deaths across all causes
+    pub fn total(&self) -> u32 {
+        self.starvation + self.natural_age + self.environmental_stress + self.predation + self.disaster + self.disease
+    }
+}
+
 /// Main resource for tracking the entire ecosystem population
-/// Manages individual creatures and ecosystem-wide statistics
-#[derive(Resource, Clone, Debug)]
+/// Manages individual creatures (or cohorts, in large gardens) and ecosystem-wide statistics
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy", derive(bevy::prelude::Resource))]
 pub struct EcosystemPopulation {
-    pub creatures: Vec<IndividualCreature>,
+    pub creatures: CreatureStore,
+    pub cohorts: Vec<Cohort>,
     pub next_creature_id: CreatureId,
     pub current_day: u32,
     
@@ -16,61 +66,123 @@ pub struct EcosystemPopulation {
     pub dead_population_by_species: HashMap<String, u32>,
     pub daily_births: HashMap<String, u32>,
     pub daily_deaths: HashMap<String, Vec<DeathCause>>,
+    pub mortality_by_species: HashMap<String, MortalityStats>,
     
-    // Matter tracking
-    pub ecosystem_matter: EcosystemMatter,
+    // Matter tracking, one set of pools per garden tile
+    pub grid: GardenGrid,
+    
+    // Seasons and weather driving each day's environmental inputs
+    pub climate: Climate,
+    
+    // Seeded random events (wildfires, floods, ...); odds may be changed between rounds
+    pub ecological_events: EcologicalEvents,
+    
+    // Trait inheritance, mutation and lineage history
+    pub genetics: Genetics,
+    
+    // Mutualism, commensalism and parasitism between species that have lived in the garden
+    pub symbiosis: Symbiosis,
+    
+    // Seeded infections in crowded species; settings may be changed between rounds
+    pub disease: Disease,
+    
+    // How quickly dead matter breaks down without decomposers; may be changed between rounds
+    pub decay: Decay,
+    
+    // Population limits
+    pub carrying_capacity: CarryingCapacity,
+    
+    // How scarce food is shared; may be changed between rounds
+    pub feeding_policy: FeedingPolicyKind,
+    
+    // Living population above which creatures are simulated as cohorts (None = always individuals)
+    pub cohort_threshold: Option<u32>,
 }
 
 impl Default for EcosystemPopulation {
     fn default() -> Self {
         Self {
-            creatures: Vec::new(),
+            creatures: CreatureStore::new(),
+            cohorts: Vec::new(),
             next_creature_id: 1,
             current_day: 0,
             living_population_by_species: HashMap::new(),
             dead_population_by_species: HashMap::new(),
             daily_births: HashMap::new(),
             daily_deaths: HashMap::new(),
-            ecosystem_matter: EcosystemMatter::default(),
+            mortality_by_species: HashMap::new(),
+            grid: GardenGrid::default(),
+            climate: Climate::default(),
+            ecological_events: EcologicalEvents::default(),
+            genetics: Genetics::default(),
+            symbiosis: Symbiosis::default(),
+            disease: Disease::default(),
+            decay: Decay::default(),
+            carrying_capacity: CarryingCapacity::default(),
+            feeding_policy: FeedingPolicyKind::default(),
+            cohort_threshold: Some(super::cohort::DEFAULT_COHORT_THRESHOLD),
         }
     }
 }
 
 impl EcosystemPopulation {
-    /// Add a new creature to the ecosystem
-    pub fn add_creature(&mut self, species: Species, initial_biomass_amounts: (u32, u32)) -> CreatureId {
+    /// Hand out the next unused creature id
+    pub fn allocate_creature_id(&mut self) -> CreatureId {
         let creature_id = self.next_creature_id;
         self.next_creature_id += 1;
+        creature_id
+    }
+
+    /// Add a new creature to the middle of the garden
+    pub fn add_creature(&mut self, species: impl Into<Arc<Species>>, initial_biomass_amounts: (u32, u32)) -> CreatureId {
+        let center = self.grid.center();
+        self.add_creature_at(species, initial_biomass_amounts, center)
+    }
+
+    /// Add a new creature to the ecosystem on a specific tile
+    pub fn add_creature_at(&mut self, species: impl Into<Arc<Species>>, initial_biomass_amounts: (u32, u32), tile: TilePosition) -> CreatureId {
+        let species = species.into();
+        let creature_id = self.allocate_creature_id();
         
-        let introduction_order = self.creatures
-            .iter()
-            .filter(|c| c.introduction_day == self.current_day)
-            .count() as u32;
+        let introduction_order = self.creatures.introductions_on(self.current_day);
         
         let biomass = super::CreatureBiomass::new(initial_biomass_amounts.0, initial_biomass_amounts.1);
-        let creature = IndividualCreature::new(
+        let mut creature = IndividualCreature::new(
             creature_id,
             species.clone(),
             biomass,
             self.current_day,
             introduction_order,
         );
+        creature.tile = self.grid.clamp(tile);
+        
+        // Catalogue relationships are known from the start; the garden learns any others when their species first arrives
+        if !species.relationships.is_empty() {
+            let root = self.genetics.lineages.root_of(species.name);
+            self.symbiosis.register(root, &species);
+        }
         
         // Update population statistics
         let species_name = species.name.to_string();
         *self.living_population_by_species.entry(species_name.clone()).or_insert(0) += 1;
         *self.daily_births.entry(species_name).or_insert(0) += 1;
         
-        self.creatures.push(creature);
+        self.creatures.insert(creature);
         creature_id
     }
 
-    /// Add a mature creature (for pairs, breeding stock, etc.)
-    pub fn add_mature_creature(&mut self, species: Species, initial_biomass_amounts: (u32, u32)) -> CreatureId {
-        let creature_id = self.add_creature(species, initial_biomass_amounts);
+    /// Add a mature creature (for pairs, breeding stock, etc.) to the middle of the garden
+    pub fn add_mature_creature(&mut self, species: impl Into<Arc<Species>>, initial_biomass_amounts: (u32, u32)) -> CreatureId {
+        let center = self.grid.center();
+        self.add_mature_creature_at(species, initial_biomass_amounts, center)
+    }
+
+    /// Add a mature creature on a specific tile
+    pub fn add_mature_creature_at(&mut self, species: impl Into<Arc<Species>>, initial_biomass_amounts: (u32, u32), tile: TilePosition) -> CreatureId {
+        let creature_id = self.add_creature_at(species, initial_biomass_amounts, tile);
         
         // Find the creature and make it mature
-        if let Some(creature) = self.creatures.iter_mut().find(|c| c.id == creature_id) {
+        if let Some(creature) = self.creatures.get_mut(creature_id) {
             creature.maturity_stage = super::MaturityStage::Mature;
         }
         
@@ -97,22 +209,83 @@ impl EcosystemPopulation {
         self.living_population_by_species.get(species_name).copied().unwrap_or(0)
     }
 
+    /// Living population of a species' whole lineage: its original species and every variant descended from it
+    /// Variants share their original species' carrying capacity, so a diverging lineage can't outgrow it
+    pub fn lineage_population(&self, species_name: &str) -> u32 {
+        let lineages = &self.genetics.lineages;
+        let root = lineages.root_of(species_name);
+        self.living_population_by_species
+            .iter()
+            .filter(|(name, _)| lineages.root_of(name) == root)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    /// Get the carrying capacity for a species under the current garden rules
+    pub fn carrying_capacity_for(&self, species: &Species) -> u32 {
+        self.carrying_capacity.capacity_for(species, &self.grid)
+    }
+
+    /// Get density pressure on a species (0.0 = none, 1.0 = at capacity)
+    pub fn density_pressure(&self, species: &Species) -> f32 {
+        self.carrying_capacity.density_pressure(
+            self.lineage_population(species.name),
+            self.carrying_capacity_for(species),
+        )
+    }
+
+    /// Get the current season
+    pub fn season(&self) -> Season {
+        self.climate.season_on(self.current_day)
+    }
+
+    /// Get today's weather
+    pub fn weather(&self) -> Weather {
+        self.climate.weather_on(self.current_day)
+    }
+
     /// Get total living population
     pub fn total_population(&self) -> u32 {
         self.living_population_by_species.values().sum()
     }
 
+    /// Whether a tile's own resources suit a newly placed member of a species:
+    /// its growth environment is in range and every food of at least one of its diets is on the tile
+    pub fn is_suitable_tile(&self, species: &Species, tile: TilePosition) -> bool {
+        let matter = self.grid.tile(tile);
+        let has_food = species.feeding_requirements.diets().any(|(requirements, _)| {
+            requirements.iter().all(|(matter_type, amount)| matter.get_amount(*matter_type) >= *amount)
+        });
+
+        has_food && super::environment::EnvironmentPhase::within_ranges(&species.growth_requirements.environmental_factors, matter, &self.grid)
+    }
+
+    /// Get living population on each occupied tile
+    pub fn population_by_tile(&self) -> HashMap<TilePosition, u32> {
+        let mut by_tile = HashMap::new();
+        for creature in self.living_creatures() {
+            *by_tile.entry(creature.tile).or_insert(0) += 1;
+        }
+        for cohort in &self.cohorts {
+            *by_tile.entry(cohort.member.tile).or_insert(0) += cohort.count;
+        }
+        by_tile
+    }
+
     /// Get population by kingdom
     pub fn population_by_kingdom(&self) -> (u32, u32, u32) {
         let mut plant_pop = 0;
         let mut animal_pop = 0;
         let mut fungi_pop = 0;
 
-        for creature in self.living_creatures() {
+        let members = self.living_creatures()
+            .map(|creature| (creature, 1))
+            .chain(self.cohorts.iter().map(|cohort| (&cohort.member, cohort.count)));
+        for (creature, count) in members {
             match creature.species.kingdom {
-                crate::gameplay::species::Kingdom::Plant => plant_pop += 1,
-                crate::gameplay::species::Kingdom::Animal => animal_pop += 1,
-                crate::gameplay::species::Kingdom::Fungi => fungi_pop += 1,
+                crate::gameplay::species::Kingdom::Plant => plant_pop += count,
+                crate::gameplay::species::Kingdom::Animal => animal_pop += count,
+                crate::gameplay::species::Kingdom::Fungi => fungi_pop += count,
             }
         }
 
@@ -123,103 +296,207 @@ impl EcosystemPopulation {
     pub fn total_living_biomass(&self) -> HashMap<MatterType, u32> {
         let mut totals = HashMap::new();
         
-        for creature in self.living_creatures() {
+        let biomasses = self.living_creatures()
+            .map(|creature| creature.biomass.clone())
+            .chain(self.cohorts.iter().map(|cohort| cohort.total_biomass()));
+        for biomass in biomasses {
             let plant_matter = totals.entry(MatterType::PlantMatter).or_insert(0);
-            *plant_matter += creature.biomass.plant_matter;
+            *plant_matter += biomass.plant_matter;
             
             let animal_matter = totals.entry(MatterType::AnimalMatter).or_insert(0);
-            *animal_matter += creature.biomass.animal_matter;
+            *animal_matter += biomass.animal_matter;
         }
         
         totals
     }
 
     /// Remove dead creatures and convert their biomass to dead matter
-    pub fn remove_dead_creatures(&mut self) -> Vec<(IndividualCreature, DeathCause)> {
+    /// Every dead creature must have been given a cause by `die`; one without is reported as an invariant violation in `phase`
+    pub fn remove_dead_creatures(&mut self, phase: &'static str) -> Result<Vec<(IndividualCreature, DeathCause)>, PhaseError> {
         let mut removed = Vec::new();
         
-        // Find dead creatures and process their matter
-        let mut to_remove = Vec::new();
-        for (index, creature) in self.creatures.iter().enumerate() {
-            if !creature.is_alive() {
-                // Convert biomass to dead matter
-                let (dead_matter_type, amount) = creature.get_death_matter();
+        // Remove dead creatures in one pass, then process their matter
+        for creature in self.creatures.remove_where(|c| !c.is_alive()) {
+            // Convert biomass to dead matter
+            for (dead_matter_type, amount) in creature.get_death_matter() {
                 if amount > 0 {
-                    self.ecosystem_matter.add_matter(dead_matter_type, amount);
+                    self.grid.tile_mut(creature.tile).add_matter(dead_matter_type, amount);
                 }
-                
-                // Update statistics
-                let species_name = creature.species.name.to_string();
-                *self.living_population_by_species.entry(species_name.clone()).or_insert(0) = 
-                    self.living_population_by_species.get(&species_name).unwrap_or(&0).saturating_sub(1);
-                *self.dead_population_by_species.entry(species_name.clone()).or_insert(0) += 1;
-                
-                to_remove.push(index);
-                removed.push((creature.clone(), DeathCause::NaturalAge)); // Default cause for now
             }
+            
+            // Update statistics
+            let Some(cause) = creature.death_cause.clone() else {
+                return Err(invariants::missing_death_cause(phase, &creature));
+            };
+            self.record_deaths(creature.species.name, &cause, 1);
+            
+            removed.push((creature, cause));
         }
         
-        // Remove in reverse order to maintain indices
-        for &index in to_remove.iter().rev() {
-            self.creatures.remove(index);
+        Ok(removed)
+    }
+
+    /// Update population statistics for creatures of one species dying of the same cause
+    pub fn record_deaths(&mut self, species_name: &str, cause: &DeathCause, count: u32) {
+        if let Some(living) = self.living_population_by_species.get_mut(species_name) {
+            *living = living.saturating_sub(count);
         }
-        
-        removed
+        *self.dead_population_by_species.entry(species_name.to_string()).or_insert(0) += count;
+        self.daily_deaths
+            .entry(species_name.to_string())
+            .or_default()
+            .extend(std::iter::repeat_n(cause.clone(), count as usize));
+        let stats = self.mortality_by_species.entry(species_name.to_string()).or_default();
+        for _ in 0..count {
+            stats.record(cause);
+        }
+    }
+
+    /// Whether creatures are currently simulated as cohorts
+    pub fn in_cohort_mode(&self) -> bool {
+        !self.cohorts.is_empty()
+    }
+
+    /// Species of every creature and cohort by id, so events naming either can be attributed
+    pub fn species_by_id(&self) -> impl Iterator<Item = (CreatureId, &'static str)> + '_ {
+        self.creatures.iter()
+            .map(|creature| (creature.id, creature.species.name))
+            .chain(self.cohorts.iter().map(|cohort| (cohort.id(), cohort.member.species.name)))
+    }
+
+    /// Find a cohort by its id
+    pub fn cohort(&self, cohort_id: CreatureId) -> Option<&Cohort> {
+        self.cohorts.iter().find(|cohort| cohort.id() == cohort_id)
+    }
+
+    /// Find a cohort by its id for modification
+    pub fn cohort_mut(&mut self, cohort_id: CreatureId) -> Option<&mut Cohort> {
+        self.cohorts.iter_mut().find(|cohort| cohort.id() == cohort_id)
+    }
+
+    /// Add a new cohort of `count` creatures (e.g. offspring born in cohort mode)
+    pub fn add_cohort(&mut self, member: IndividualCreature, count: u32) -> CreatureId {
+        let species_name = member.species.name.to_string();
+        *self.living_population_by_species.entry(species_name.clone()).or_insert(0) += count;
+        *self.daily_births.entry(species_name).or_insert(0) += count;
+
+        let cohort_id = member.id;
+        self.cohorts.push(Cohort::new(member, count));
+        cohort_id
+    }
+
+    /// Switch between individual and cohort simulation based on population size
+    /// Cohort mode starts above the threshold and ends once the garden shrinks below half of it,
+    /// so a population hovering near the threshold doesn't flip modes every day
+    pub fn update_population_mode(&mut self) {
+        let total = self.total_population();
+        let use_cohorts = match self.cohort_threshold {
+            Some(threshold) if self.in_cohort_mode() => total > threshold / 2,
+            Some(threshold) => total > threshold,
+            None => false,
+        };
+
+        if use_cohorts {
+            self.gather_into_cohorts();
+        } else if self.in_cohort_mode() {
+            self.expand_cohorts();
+        }
+    }
+
+    /// Fold every individual creature (including newly played ones) into cohorts
+    fn gather_into_cohorts(&mut self) {
+        let individuals = self.creatures.remove_where(|_| true);
+        self.cohorts.extend(individuals.into_iter().map(|creature| Cohort::new(creature, 1)));
+        super::cohort::merge_cohorts(&mut self.cohorts, &mut self.grid);
+    }
+
+    /// Turn every cohort back into individual creatures with fresh ids
+    fn expand_cohorts(&mut self) {
+        for cohort in std::mem::take(&mut self.cohorts) {
+            for _ in 0..cohort.count {
+                let mut creature = cohort.member.clone();
+                creature.id = self.allocate_creature_id();
+                creature.introduction_order = self.creatures.introductions_on(creature.introduction_day);
+                self.creatures.insert(creature);
+            }
+        }
+    }
+
+    /// Reset daily statistics before simulating a day
+    /// Births and deaths recorded during the day stay readable until the next one starts
+    pub fn begin_day(&mut self) {
+        self.daily_births.clear();
+        self.daily_deaths.clear();
     }
 
     /// Advance to next day
     pub fn advance_day(&mut self) {
         self.current_day += 1;
         
-        // Clear daily statistics
-        self.daily_births.clear();
-        self.daily_deaths.clear();
-        
         // Age all living creatures
         for creature in self.living_creatures_mut() {
             creature.age_one_day();
         }
+        for cohort in &mut self.cohorts {
+            cohort.member.age_one_day();
+        }
+        
+        self.record_lineages();
         
-        // Apply daily environmental inputs
-        self.ecosystem_matter.apply_daily_inputs();
+        // Nutrients, water and dead matter spread between tiles overnight
+        self.grid.diffuse();
+        
+        // Apply the new day's seasonal and weather inputs
+        let inputs = self.climate.daily_inputs(self.current_day);
+        self.grid.apply_daily_inputs(&inputs);
+    }
+
+    /// Record each living species' mean traits for the day that just ended
+    fn record_lineages(&mut self) {
+        let mut means: HashMap<&'static str, (u32, Genome)> = HashMap::new();
+        let members = self.creatures.iter()
+            .filter(|c| c.is_alive())
+            .map(|creature| (creature, 1))
+            .chain(self.cohorts.iter().map(|cohort| (&cohort.member, cohort.count)));
+
+        for (creature, count) in members {
+            let (population, mean) = means.entry(creature.species.name).or_insert((0, creature.genome));
+            if *population > 0 {
+                *mean = mean.blend(*population, &creature.genome, count);
+            }
+            *population += count;
+        }
+
+        let day = self.current_day - 1;
+        for (species, (population, mean)) in means {
+            self.genetics.lineages.record(species, TraitSample { day, population, mean });
+        }
     }
 
     /// Get creatures sorted by trophic level and introduction order
     /// This is crucial for proper feeding phase processing
     pub fn creatures_by_trophic_order(&self) -> Vec<&IndividualCreature> {
-        let mut creatures: Vec<&IndividualCreature> = self.living_creatures().collect();
-        
-        // Sort by trophic level first, then by introduction order
-        creatures.sort_by(|a, b| {
-            let trophic_a = self.get_trophic_level(a);
-            let trophic_b = self.get_trophic_level(b);
-            
-            trophic_a.cmp(&trophic_b)
-                .then_with(|| a.introduction_day.cmp(&b.introduction_day))
-                .then_with(|| a.introduction_order.cmp(&b.introduction_order))
-        });
-        
-        creatures
-    }
-
-    /// Get trophic level for feeding order (lower numbers feed first)
-    fn get_trophic_level(&self, creature: &IndividualCreature) -> u8 {
-        match creature.species.kingdom {
-            // Decomposers feed first
-            crate::gameplay::species::Kingdom::Fungi => 0,
-            // TODO: Distinguish decomposer animals (earthworms) from regular animals
-            // For now, treat all animals as consumers
-            crate::gameplay::species::Kingdom::Animal => {
-                // Check if this is a decomposer animal (earthworm, etc.)
-                if creature.species.name.contains("Earthworm") || creature.species.name.contains("Worm") {
-                    0  // Decomposer
-                } else {
-                    2  // Consumer (herbivore/carnivore determined by what they eat)
-                }
-            },
-            // Plants are primary producers
-            crate::gameplay::species::Kingdom::Plant => 1,
-        }
+        self.living_trophic_groups()
+            .into_iter()
+            .flat_map(|(_level, creature_ids)| creature_ids)
+            .filter_map(|creature_id| self.creatures.get(creature_id))
+            .collect()
+    }
+
+    /// Get living creature ids grouped by trophic level (lower levels feed first), each group in introduction order
+    pub fn living_trophic_groups(&self) -> Vec<(u8, Vec<CreatureId>)> {
+        self.creatures
+            .trophic_groups()
+            .map(|(level, creature_ids)| {
+                let living = creature_ids
+                    .iter()
+                    .copied()
+                    .filter(|id| self.creatures.get(*id).is_some_and(|c| c.is_alive()))
+                    .collect::<Vec<_>>();
+                (level, living)
+            })
+            .filter(|(_level, creature_ids)| !creature_ids.is_empty())
+            .collect()
     }
 
     /// Get ecosystem summary for debugging/display
@@ -236,7 +513,7 @@ impl EcosystemPopulation {
         summary.insert("Fungi".to_string(), fungi.to_string());
         
         // Add matter summary
-        for (matter_name, amount) in self.ecosystem_matter.get_matter_summary() {
+        for (matter_name, amount) in self.grid.total_matter().get_matter_summary() {
             summary.insert(format!("Matter: {}", matter_name), amount.to_string());
         }
         
@@ -247,42 +524,63 @@ impl EcosystemPopulation {
 // ===== EVENT HANDLERS =====
 
 /// Handle adding species to the ecosystem
+#[cfg(feature = "bevy")]
 pub fn handle_add_species_to_ecosystem_event(
     mut ecosystem: ResMut<EcosystemPopulation>,
+    mut event_log: ResMut<EcosystemLog>,
     mut add_species_events: EventReader<super::AddSpeciesToEcosystemEvent>,
 ) {
     for event in add_species_events.read() {
-        println!("Adding {} to ecosystem with biomass ({}, {})", 
-                 event.species.name, 
-                 event.starting_biomass.0, 
-                 event.starting_biomass.1);
-        
-        ecosystem.add_creature(
+        let creature_id = ecosystem.add_creature_at(
             event.species.clone(), 
-            event.starting_biomass
+            event.starting_biomass,
+            event.location,
+        );
+
+        event_log.record(
+            ecosystem.current_day,
+            LogEventKind::Introduced,
+            Some(event.species.name),
+            format!("{} #{} introduced to the garden at tile ({}, {})", event.species.name, creature_id, event.location.x, event.location.y),
         );
     }
 }
 
 /// Handle daily simulation events
+#[cfg(feature = "bevy")]
 pub fn handle_simulate_day_event(
     mut ecosystem: ResMut<EcosystemPopulation>,
+    daily_simulation: Res<super::DailySimulation>,
+    mut event_log: ResMut<EcosystemLog>,
     mut simulate_events: EventReader<super::SimulateDayEvent>,
+    mut failure_events: EventWriter<super::SimulationFailedEvent>,
+    mut lifecycle_events: super::LifecycleEventWriters,
+    mut halted: Local<bool>,
 ) {
     for _event in simulate_events.read() {
-        let daily_simulation = super::DailySimulation::new();
+        // A strict-mode halt stops the garden where it broke; later days would only build on the broken state
+        if *halted {
+            continue;
+        }
+
+        // Remember species before the day runs, since dead creatures and cohorts are removed during it
+        let mut species_by_id: HashMap<CreatureId, &'static str> = ecosystem.species_by_id().collect();
         
         match daily_simulation.simulate_day(&mut ecosystem) {
             Ok(result) => {
-                let summary = result.get_summary();
-                println!("Day {} simulation completed: {} births, {} deaths, {} total creatures", 
-                         summary.day, 
-                         summary.births,
-                         summary.deaths,
-                         summary.final_population);
+                species_by_id.extend(ecosystem.species_by_id());
+                event_log.record_daily_result(&result, &species_by_id);
+                lifecycle_events.publish(&result, &species_by_id);
             }
             Err(error) => {
-                eprintln!("Day {} simulation failed: {}", ecosystem.current_day, error);
+                *halted = matches!(error, PhaseError::Halted { .. });
+                // Outside strict mode the garden has already been rolled back; let the player know the day didn't happen
+                // The log needs no rollback: the day's lifecycle events are only recorded once it succeeds, and the
+                // player's own actions logged before it (discards, introductions) really happened and aren't undone
+                failure_events.write(super::SimulationFailedEvent {
+                    day: ecosystem.current_day,
+                    message: error.to_string(),
+                });
             }
         }
     }
@@ -1,9 +1,15 @@
 #![allow(unused_variables)]
 
+use std::collections::{BTreeMap, HashMap};
 use super::{
     LifecyclePhase, PhaseResult, PhaseError, LifecycleEvent,
-    EcosystemPopulation, MaturityStage, CreatureBiomass
+    EcosystemPopulation, EcosystemMatter, MatterType, CreatureBiomass, CreatureId, IndividualCreature, SymbiosisMap, TilePosition, scale_trait
 };
+use super::invariants::{self, INVARIANT_CHECKS_ENABLED};
+use crate::gameplay::species::Species;
+
+/// Least biomass a creature needs to breed; fruiting plants never fruit below it
+const MIN_BREEDING_BIOMASS: u32 = 2;
 
 /// Reproduction phase - mature, well-fed creatures create offspring
 /// Parent biomass is converted to offspring biomass (matter conservation)
@@ -15,9 +21,9 @@ impl ReproductionPhase {
     }
 
     /// Check if a creature can reproduce based on basic requirements
-    fn can_reproduce(&self, creature: &crate::gameplay::lifecycle::IndividualCreature) -> bool {
-        // Must be mature
-        if creature.maturity_stage != MaturityStage::Mature {
+    fn can_reproduce(&self, creature: &crate::gameplay::lifecycle::IndividualCreature, day: u32, symbiosis: &SymbiosisMap) -> bool {
+        // Must be in a life stage that breeds
+        if !creature.stage_traits().can_reproduce {
             return false;
         }
 
@@ -27,18 +33,37 @@ impl ReproductionPhase {
         }
 
         // Must have sufficient biomass
-        if creature.biomass.total() < 2 {
+        if creature.biomass.total() < MIN_BREEDING_BIOMASS {
+            return false;
+        }
+
+        // Must be within the species' environmental requirements
+        if !creature.in_reproduction_environment {
+            return false;
+        }
+
+        // Must have waited out the species' cooldown, as stretched or shortened by the creature's traits
+        let cooldown = scale_trait(creature.species.reproduction_requirements.cooldown_days, creature.genome.reproduction_cooldown);
+        if creature.last_reproduction_day.is_some_and(|last| day < last + cooldown) {
+            return false;
+        }
+
+        // Must have any partner species it depends on for breeding nearby
+        if !symbiosis.can_breed(creature.species.name, creature.tile) {
             return false;
         }
 
         // TODO: Check species-specific requirements
-        // - Reproduction cooldown
-        // - Environmental requirements  
-        // - Population requirements (pairs, max density, etc.)
+        // - Population requirements (pairs, etc.)
 
         true
     }
 
+    /// Which of the parent's nearby tiles an offspring disperses to, varying between parents and days
+    fn dispersal_choice(parent_id: CreatureId, day: u32) -> u32 {
+        (parent_id as u32).wrapping_add(day)
+    }
+
     /// Calculate offspring biomass from parent
     fn calculate_offspring_biomass(&self, parent: &crate::gameplay::lifecycle::IndividualCreature) -> (CreatureBiomass, CreatureBiomass) {
         // For now, simple 50/50 split
@@ -58,76 +83,244 @@ impl ReproductionPhase {
         
         (parent_remaining, offspring_biomass)
     }
+
+    /// Sow a litter as seed-sized seedlings out of the uneaten fruit on the parent's tile, if the species fruits and enough is left
+    /// Returns each seedling's biomass when the fruit paid for the litter, in which case the parent keeps its own biomass
+    fn sow_from_fruit(
+        species: &Species,
+        matter: &mut EcosystemMatter,
+        litter: u32,
+        events: &mut Vec<LifecycleEvent>,
+    ) -> Option<CreatureBiomass> {
+        let (plant_matter, animal_matter) = species.starting_biomass();
+        let needed = (plant_matter + animal_matter) * litter;
+        if species.reproduction_requirements.fruit_set == 0 || needed == 0 || !matter.can_consume(MatterType::Fruit, needed) {
+            return None;
+        }
+
+        matter.consume_matter(MatterType::Fruit, needed);
+        for (to_type, amount) in [(MatterType::PlantMatter, plant_matter), (MatterType::AnimalMatter, animal_matter)] {
+            if amount > 0 {
+                events.push(LifecycleEvent::MatterTransformed { from_type: MatterType::Fruit, to_type, amount: amount * litter });
+            }
+        }
+        Some(CreatureBiomass::new(plant_matter, animal_matter))
+    }
+
+    /// Plant biomass a breeding parent of a fruiting species sets aside as fruit, keeping enough to breed again
+    fn fruit_from(parent: &mut IndividualCreature) -> u32 {
+        let amount = parent.species.reproduction_requirements.fruit_set
+            .min(parent.biomass.plant_matter)
+            .min(parent.biomass.total().saturating_sub(MIN_BREEDING_BIOMASS));
+        parent.biomass.remove_matter(MatterType::PlantMatter, amount)
+    }
 }
 
 impl LifecyclePhase for ReproductionPhase {
     fn execute(&self, ecosystem: &mut EcosystemPopulation) -> Result<PhaseResult, PhaseError> {
         let mut events = Vec::new();
         let mut creatures_processed = 0;
+        let mut matter_transformed = 0;
         let mut new_offspring = Vec::new();
 
         // Collect reproduction candidates to avoid borrowing issues
         let mut reproduction_candidates = Vec::new();
+        let current_day = ecosystem.current_day;
+        let symbiosis = SymbiosisMap::build(ecosystem);
         for creature in ecosystem.living_creatures() {
-            if self.can_reproduce(creature) {
+            if self.can_reproduce(creature, current_day, &symbiosis) {
                 reproduction_candidates.push(creature.id);
             }
         }
 
+        // Offspring created this phase count against their lineage's carrying capacity before they are added
+        let mut pending_births: HashMap<&'static str, u32> = HashMap::new();
+        // Fruit set by today's breeders, only laid on the tiles once everyone has sown
+        let mut fruit_by_tile: BTreeMap<TilePosition, u32> = BTreeMap::new();
+
         // Process each reproduction candidate
         for parent_id in reproduction_candidates {
             creatures_processed += 1;
 
+            // Suppress reproduction once the species has reached its carrying capacity
+            let Some(species) = ecosystem.creatures.get(parent_id).map(|c| &c.species) else {
+                continue;
+            };
+            let lineage = ecosystem.genetics.lineages.root_of(species.name);
+            let pending = pending_births.get(lineage).copied().unwrap_or(0);
+            if ecosystem.lineage_population(species.name) + pending >= ecosystem.carrying_capacity_for(species) {
+                continue;
+            }
+            *pending_births.entry(lineage).or_insert(0) += 1;
+
             // Find parent creature
-            let (parent_biomass, offspring_biomass) = {
-                let parent = ecosystem.creatures.iter()
-                    .find(|c| c.id == parent_id && c.is_alive())
+            let (parent_biomass, offspring_biomass, parent_species, parent_tile, parent_genome) = {
+                let parent = ecosystem.creatures.get(parent_id)
+                    .filter(|c| c.is_alive())
                     .ok_or_else(|| PhaseError::SystemError("Parent creature not found".to_string()))?;
                 
-                self.calculate_offspring_biomass(parent)
+                let (parent_biomass, offspring_biomass) = self.calculate_offspring_biomass(parent);
+                (parent_biomass, offspring_biomass, parent.species.clone(), parent.tile, parent.genome)
             };
 
-            // Update parent biomass
-            if let Some(parent) = ecosystem.creatures.iter_mut().find(|c| c.id == parent_id) {
-                parent.biomass = parent_biomass;
+            // Fruiting plants sow a seedling from fruit left uneaten on their tile before spending their own biomass
+            let seedling = Self::sow_from_fruit(&parent_species, ecosystem.grid.tile_mut(parent_tile), 1, &mut events);
+            matter_transformed += seedling.as_ref().map_or(0, |biomass| biomass.total());
+
+            // Update parent biomass, and have fruiting plants set fruit as they breed
+            if let Some(parent) = ecosystem.creatures.get_mut(parent_id) {
+                if seedling.is_none() {
+                    parent.biomass = parent_biomass;
+                }
                 parent.last_reproduction_day = Some(ecosystem.current_day);
+                let fruit = Self::fruit_from(parent);
+                if fruit > 0 {
+                    *fruit_by_tile.entry(parent_tile).or_insert(0) += fruit;
+                }
             }
 
             // Create offspring (but don't add to ecosystem yet to avoid borrowing issues)
-            let parent_species = ecosystem.creatures.iter()
-                .find(|c| c.id == parent_id)
-                .map(|c| c.species.clone())
-                .ok_or_else(|| PhaseError::SystemError("Parent species not found".to_string()))?;
+            let offspring_biomass = seedling.unwrap_or(offspring_biomass);
 
-            new_offspring.push((parent_species, offspring_biomass, parent_id));
+            // Offspring settle on the parent's tile or a neighbouring one
+            let tile = ecosystem.grid.dispersal_tile(parent_tile, Self::dispersal_choice(parent_id, ecosystem.current_day));
+            new_offspring.push((parent_species, parent_genome, offspring_biomass, parent_id, tile));
         }
 
-        // Add all offspring to ecosystem
-        for (species, biomass, parent_id) in new_offspring {
-            let offspring_id = ecosystem.add_creature(species, (biomass.plant_matter, biomass.animal_matter));
+        // Add all offspring to ecosystem, each inheriting its parent's traits with a little mutation
+        for (parent_species, parent_genome, biomass, parent_id, tile) in new_offspring {
+            let genome = ecosystem.genetics.offspring_genome(&parent_genome, parent_id, current_day);
+            let variant = ecosystem.genetics.diverged_variant(&parent_species, &genome);
+            let species = variant.as_ref().map_or(parent_species.clone(), |(variant, _)| variant.clone());
+
+            let offspring_id = ecosystem.add_creature_at(species, (biomass.plant_matter, biomass.animal_matter), tile);
+            if let Some(offspring) = ecosystem.creatures.get_mut(offspring_id) {
+                offspring.genome = genome;
+            }
             
             events.push(LifecycleEvent::CreatureReproduced {
                 parent_id,
                 offspring_id,
             });
+            if let Some((variant, true)) = variant {
+                events.push(LifecycleEvent::VariantEmerged {
+                    creature_id: offspring_id,
+                    parent_species: parent_species.name,
+                    species: variant.name,
+                });
+            }
+        }
+
+        // Cohorts breed as a unit: as many members reproduce as carrying capacity leaves room for
+        let cohort_candidates: Vec<CreatureId> = ecosystem.cohorts.iter()
+            .filter(|cohort| self.can_reproduce(&cohort.member, current_day, &symbiosis))
+            .map(|cohort| cohort.id())
+            .collect();
+
+        for parent_cohort_id in cohort_candidates {
+            let Some(cohort) = ecosystem.cohort(parent_cohort_id) else {
+                continue;
+            };
+            creatures_processed += cohort.count;
+
+            let species = cohort.member.species.clone();
+            let parent_tile = cohort.member.tile;
+            let parent_genome = cohort.member.genome;
+            let count = cohort.count;
+            let lineage = ecosystem.genetics.lineages.root_of(species.name);
+            let pending = pending_births.get(lineage).copied().unwrap_or(0);
+            let room = ecosystem.carrying_capacity_for(&species)
+                .saturating_sub(ecosystem.lineage_population(species.name) + pending);
+            let breeding = count.min(room);
+            if breeding == 0 {
+                continue;
+            }
+            *pending_births.entry(lineage).or_insert(0) += breeding;
+
+            let (parent_biomass, offspring_biomass) = self.calculate_offspring_biomass(&cohort.member);
+
+            // Members that didn't get to breed split off into their own cohort
+            if breeding < count {
+                let mut resting = cohort.clone();
+                resting.member.id = ecosystem.allocate_creature_id();
+                resting.count = count - breeding;
+                ecosystem.cohorts.push(resting);
+            }
+
+            // The litter is only sown from fruit if there's enough on the tile for all of it
+            let seedling = Self::sow_from_fruit(&species, ecosystem.grid.tile_mut(parent_tile), breeding, &mut events);
+            matter_transformed += seedling.as_ref().map_or(0, |biomass| biomass.total() * breeding);
+
+            if let Some(parent) = ecosystem.cohort_mut(parent_cohort_id) {
+                parent.count = breeding;
+                if seedling.is_none() {
+                    parent.member.biomass = parent_biomass;
+                }
+                parent.member.last_reproduction_day = Some(current_day);
+                let fruit = Self::fruit_from(&mut parent.member) * breeding;
+                if fruit > 0 {
+                    *fruit_by_tile.entry(parent_tile).or_insert(0) += fruit;
+                }
+            }
+            let offspring_biomass = seedling.unwrap_or(offspring_biomass);
+
+            // The whole litter shares one mutated copy of the parents' traits
+            let genome = ecosystem.genetics.offspring_genome(&parent_genome, parent_cohort_id, current_day);
+            let variant = ecosystem.genetics.diverged_variant(&species, &genome);
+
+            let mut offspring = IndividualCreature::new(
+                ecosystem.allocate_creature_id(),
+                variant.as_ref().map_or(species.clone(), |(variant, _)| variant.clone()),
+                offspring_biomass,
+                current_day,
+                ecosystem.creatures.introductions_on(current_day),
+            );
+            offspring.tile = ecosystem.grid.dispersal_tile(parent_tile, Self::dispersal_choice(parent_cohort_id, current_day));
+            offspring.genome = genome;
+            let offspring_cohort_id = ecosystem.add_cohort(offspring, breeding);
+
+            events.push(LifecycleEvent::CohortReproduced {
+                parent_cohort_id,
+                offspring_cohort_id,
+                species: species.name,
+                offspring: breeding,
+            });
+            if let Some((variant, true)) = variant {
+                events.push(LifecycleEvent::VariantEmerged {
+                    creature_id: offspring_cohort_id,
+                    parent_species: species.name,
+                    species: variant.name,
+                });
+            }
+        }
+
+        // Fruit is laid after today's sowing, so animals get a chance to eat it before it can be sown; reported per tile
+        for (tile, amount) in fruit_by_tile {
+            ecosystem.grid.tile_mut(tile).add_matter(MatterType::Fruit, amount);
+            events.push(LifecycleEvent::MatterTransformed { from_type: MatterType::PlantMatter, to_type: MatterType::Fruit, amount });
+            matter_transformed += amount;
         }
 
         Ok(PhaseResult {
             creatures_processed,
-            matter_transformed: 0, // Matter is conserved in reproduction
+            matter_transformed, // Fruit set and sown; matter is otherwise conserved in reproduction
             events,
         })
     }
 
-    fn validate_preconditions(&self, _ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
-        // Validate that growth phase has been completed
-        Ok(())
+    fn validate_preconditions(&self, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+        if !INVARIANT_CHECKS_ENABLED {
+            return Ok(());
+        }
+
+        // Breeding decisions rely on today's feeding status
+        invariants::check_fed_status_fresh(self.phase_name(), ecosystem)?;
+        invariants::check_population_invariants(self.phase_name(), ecosystem)
     }
 
-    fn validate_postconditions(&self, _ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
-        // Validate that matter was conserved during reproduction
-        // Validate that new offspring have proper biomass
-        Ok(())
+    fn validate_postconditions(&self, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+        // Offspring must be counted and receive fresh ids
+        invariants::check_population_invariants(self.phase_name(), ecosystem)
     }
 
     fn phase_name(&self) -> &'static str {
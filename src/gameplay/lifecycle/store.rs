@@ -0,0 +1,113 @@
+use std::collections::{BTreeMap, HashMap};
+use crate::gameplay::species::{Kingdom, Species};
+use super::{CreatureId, IndividualCreature};
+
+/// Trophic level for feeding order (lower numbers feed first)
+pub fn trophic_level(species: &Species) -> u8 {
+    match species.kingdom {
+        // Decomposers feed first
+        Kingdom::Fungi => 0,
+        // TODO: Distinguish decomposer animals (earthworms) from regular animals
+        // For now, treat all animals as consumers
+        Kingdom::Animal => {
+            // Check if this is a decomposer animal (earthworm, etc.)
+            if species.name.contains("Earthworm") || species.name.contains("Worm") {
+                0  // Decomposer
+            } else {
+                2  // Consumer (herbivore/carnivore determined by what they eat)
+            }
+        },
+        // Plants are primary producers
+        Kingdom::Plant => 1,
+    }
+}
+
+/// Creature storage indexed by `CreatureId`
+/// Iteration follows insertion order, which is also (introduction day, introduction order) order,
+/// so results stay deterministic while lookups, per-day introduction counts and trophic groups are cached
+#[derive(Clone, Debug, Default)]
+pub struct CreatureStore {
+    creatures: Vec<IndividualCreature>,
+    index: HashMap<CreatureId, usize>,
+    introductions_by_day: HashMap<u32, u32>,
+    trophic_groups: BTreeMap<u8, Vec<CreatureId>>,
+}
+
+impl CreatureStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.creatures.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.creatures.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, IndividualCreature> {
+        self.creatures.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, IndividualCreature> {
+        self.creatures.iter_mut()
+    }
+
+    pub fn contains(&self, creature_id: CreatureId) -> bool {
+        self.index.contains_key(&creature_id)
+    }
+
+    pub fn get(&self, creature_id: CreatureId) -> Option<&IndividualCreature> {
+        self.index.get(&creature_id).map(|&slot| &self.creatures[slot])
+    }
+
+    pub fn get_mut(&mut self, creature_id: CreatureId) -> Option<&mut IndividualCreature> {
+        self.index.get(&creature_id).map(|&slot| &mut self.creatures[slot])
+    }
+
+    /// Number of creatures ever introduced on a given day (used for introduction order)
+    pub fn introductions_on(&self, day: u32) -> u32 {
+        self.introductions_by_day.get(&day).copied().unwrap_or(0)
+    }
+
+    /// Add a creature to the end of the iteration order
+    pub fn insert(&mut self, creature: IndividualCreature) {
+        debug_assert!(!self.contains(creature.id), "creature {} inserted twice", creature.id);
+
+        *self.introductions_by_day.entry(creature.introduction_day).or_insert(0) += 1;
+        self.trophic_groups
+            .entry(trophic_level(&creature.species))
+            .or_default()
+            .push(creature.id);
+        self.index.insert(creature.id, self.creatures.len());
+        self.creatures.push(creature);
+    }
+
+    /// Remove every creature matching the predicate in a single pass, preserving the order of the rest
+    pub fn remove_where(&mut self, mut predicate: impl FnMut(&IndividualCreature) -> bool) -> Vec<IndividualCreature> {
+        let removed: Vec<IndividualCreature> = self.creatures
+            .extract_if(.., |creature| predicate(creature))
+            .collect();
+        if removed.is_empty() {
+            return removed;
+        }
+
+        self.index = self.creatures
+            .iter()
+            .enumerate()
+            .map(|(slot, creature)| (creature.id, slot))
+            .collect();
+        for group in self.trophic_groups.values_mut() {
+            group.retain(|id| self.index.contains_key(id));
+        }
+        self.trophic_groups.retain(|_, group| !group.is_empty());
+
+        removed
+    }
+
+    /// Creature ids grouped by trophic level (lowest first), each group in introduction order
+    pub fn trophic_groups(&self) -> impl Iterator<Item = (u8, &[CreatureId])> {
+        self.trophic_groups.iter().map(|(level, ids)| (*level, ids.as_slice()))
+    }
+}
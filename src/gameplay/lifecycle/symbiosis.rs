@@ -0,0 +1,326 @@
+use std::collections::HashMap;
+use super::{
+    LifecyclePhase, PhaseResult, PhaseError, LifecycleEvent,
+    EcosystemPopulation, MatterType, CreatureId, TilePosition, GardenGrid
+};
+use super::invariants::{self, INVARIANT_CHECKS_ENABLED};
+use crate::gameplay::species::{Species, SpeciesRelationship, RelationshipEffect, BiomassComposition, get_all_species};
+
+/// Relationships between species known to the garden, declared by every species in the catalogue
+/// and by any other species that has lived in it
+/// Variants share their original species' relationships, so everything is keyed by original species name
+#[derive(Clone, Debug)]
+pub struct Symbiosis {
+    known: HashMap<&'static str, Vec<SpeciesRelationship>>,
+}
+
+impl Default for Symbiosis {
+    fn default() -> Self {
+        Self::from_species(get_all_species().values())
+    }
+}
+
+impl Symbiosis {
+    /// Relationships declared by the given species, known before any of them has been played,
+    /// so a partner that needs one of them to breed can't breed until it arrives
+    pub fn from_species<'a>(species: impl IntoIterator<Item = &'a Species>) -> Self {
+        let mut symbiosis = Self { known: HashMap::new() };
+        for species in species {
+            symbiosis.register(species.name, species);
+        }
+        symbiosis
+    }
+
+    /// Remember the relationships of a species from outside the catalogue the first time it enters the garden
+    pub fn register(&mut self, root: &'static str, species: &Species) {
+        if !species.relationships.is_empty() && !self.known.contains_key(root) {
+            self.known.insert(root, species.relationships.clone());
+        }
+    }
+
+    /// Every known relationship as (declaring species, relationship)
+    pub fn relationships(&self) -> impl Iterator<Item = (&'static str, &SpeciesRelationship)> {
+        self.known.iter().flat_map(|(species, relationships)| relationships.iter().map(move |relationship| (*species, relationship)))
+    }
+}
+
+/// Where today's relationships take effect, worked out from which species live on which tiles
+/// A partner is affected on the tile a species lives on and on the tiles next to it
+/// Per-tile tables are in the grid's row-major order
+#[derive(Debug, Default)]
+pub struct SymbiosisMap {
+    width: u32,
+    /// Original species of each variant
+    roots: HashMap<&'static str, &'static str>,
+    /// Summed feeding bonus on each tile, for each original species that partners feed
+    feeding_bonuses: Vec<(&'static str, Vec<f32>)>,
+    /// Whether each tile has every partner an original species needs to breed
+    breeding_partners: Vec<(&'static str, Vec<bool>)>,
+}
+
+impl SymbiosisMap {
+    pub fn build(ecosystem: &EcosystemPopulation) -> Self {
+        let grid = &ecosystem.grid;
+        let lineages = &ecosystem.genetics.lineages;
+        let mut map = Self {
+            width: grid.width(),
+            roots: lineages.variants().map(|variant| (variant, lineages.root_of(variant))).collect(),
+            ..Default::default()
+        };
+        if ecosystem.symbiosis.known.is_empty() {
+            return map;
+        }
+
+        // Only species that declare relationships affect anyone, so only their tiles matter
+        let mut occupied: Vec<(&'static str, Vec<bool>)> = Vec::new();
+        let members = ecosystem.living_creatures()
+            .chain(ecosystem.cohorts.iter().map(|cohort| &cohort.member))
+            .filter(|creature| !creature.species.relationships.is_empty());
+        for creature in members {
+            let root = map.root(creature.species.name);
+            let tiles = Self::table(&mut occupied, root, grid.tile_count(), false);
+            tiles[grid.index_of(creature.tile)] = true;
+        }
+
+        for (species, relationship) in ecosystem.symbiosis.relationships() {
+            let reach = Self::reach(grid, occupied.iter().find(|(root, _)| *root == species).map(|(_, tiles)| tiles.as_slice()));
+            match relationship.effect {
+                RelationshipEffect::BoostsFeeding { bonus } => {
+                    let bonuses = Self::table(&mut map.feeding_bonuses, relationship.partner, grid.tile_count(), 0.0);
+                    for (tile_bonus, near) in bonuses.iter_mut().zip(&reach) {
+                        if *near {
+                            *tile_bonus += bonus;
+                        }
+                    }
+                }
+                RelationshipEffect::RequiredForReproduction => {
+                    let breeding = Self::table(&mut map.breeding_partners, relationship.partner, grid.tile_count(), true);
+                    for (can_breed, near) in breeding.iter_mut().zip(&reach) {
+                        *can_breed &= *near;
+                    }
+                }
+                // Parasites act on individual hosts in the symbiosis phase
+                RelationshipEffect::TakesBiomass { .. } => {}
+            }
+        }
+
+        map
+    }
+
+    /// A species' per-tile table, added with every tile set to `initial` if it isn't there yet
+    fn table<'a, T: Clone>(tables: &'a mut Vec<(&'static str, Vec<T>)>, species: &'static str, tile_count: usize, initial: T) -> &'a mut [T] {
+        let index = match tables.iter().position(|(name, _)| *name == species) {
+            Some(index) => index,
+            None => {
+                tables.push((species, vec![initial; tile_count]));
+                tables.len() - 1
+            }
+        };
+        &mut tables[index].1
+    }
+
+    /// Which tiles are on or next to an occupied tile
+    fn reach(grid: &GardenGrid, occupied: Option<&[bool]>) -> Vec<bool> {
+        let mut reach = vec![false; grid.tile_count()];
+        for (index, _) in occupied.unwrap_or(&[]).iter().enumerate().filter(|(_, occupied)| **occupied) {
+            let tile = grid.position_of(index);
+            reach[index] = true;
+            for neighbour in grid.neighbours(tile) {
+                reach[grid.index_of(neighbour)] = true;
+            }
+        }
+        reach
+    }
+
+    fn root(&self, species: &'static str) -> &'static str {
+        self.roots.get(species).copied().unwrap_or(species)
+    }
+
+    fn tile_index(&self, tile: TilePosition) -> usize {
+        (tile.y * self.width + tile.x) as usize
+    }
+
+    /// Fraction added to a species' feeding efficiency on a tile by nearby partners
+    pub fn feeding_bonus(&self, species: &'static str, tile: TilePosition) -> f32 {
+        let root = self.root(species);
+        self.feeding_bonuses.iter()
+            .find(|(name, _)| *name == root)
+            .and_then(|(_, bonuses)| bonuses.get(self.tile_index(tile)).copied())
+            .unwrap_or(0.0)
+    }
+
+    /// Whether every partner a species needs to breed lives near a tile
+    pub fn can_breed(&self, species: &'static str, tile: TilePosition) -> bool {
+        let root = self.root(species);
+        self.breeding_partners.iter()
+            .find(|(name, _)| *name == root)
+            .and_then(|(_, breeding)| breeding.get(self.tile_index(tile)).copied())
+            .unwrap_or(true)
+    }
+}
+
+/// Symbiosis phase - parasites draw biomass from nearby hosts
+/// Runs after feeding; feeding boosts and breeding partners are applied by the feeding and reproduction phases
+/// Parasitism is simulated between individuals; cohorts neither host nor carry parasites
+pub struct SymbiosisPhase;
+
+impl SymbiosisPhase {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// The biomass type a parasite builds from what it takes; mixed-composition parasites build both in proportion
+    fn biomass_type(species: &Species) -> MatterType {
+        match species.biomass_composition {
+            BiomassComposition::Plant => MatterType::PlantMatter,
+            BiomassComposition::Animal | BiomassComposition::Mixed { .. } => MatterType::AnimalMatter,
+        }
+    }
+}
+
+impl LifecyclePhase for SymbiosisPhase {
+    fn execute(&self, ecosystem: &mut EcosystemPopulation) -> Result<PhaseResult, PhaseError> {
+        let mut events = Vec::new();
+        let mut creatures_processed = 0;
+        let mut matter_transformed = 0;
+
+        // Collect parasites first to avoid borrowing issues; dormant parasites don't feed
+        let mut parasites: Vec<(CreatureId, TilePosition, &'static str, u32)> = Vec::new();
+        for creature in ecosystem.living_creatures().filter(|c| !c.dormant) {
+            for relationship in &creature.species.relationships {
+                if let RelationshipEffect::TakesBiomass { amount } = relationship.effect {
+                    parasites.push((creature.id, creature.tile, relationship.partner, amount));
+                }
+            }
+        }
+        parasites.sort_by_key(|(id, ..)| *id);
+
+        for (parasite_id, tile, partner, amount) in parasites {
+            creatures_processed += 1;
+
+            // Each parasite feeds on the nearby host with the most biomass; hosts always keep at least one unit
+            let mut nearby = ecosystem.grid.neighbours(tile);
+            nearby.push(tile);
+            let lineages = &ecosystem.genetics.lineages;
+            let host = ecosystem.living_creatures()
+                .filter(|host| host.id != parasite_id && host.biomass.total() > 1 && nearby.contains(&host.tile))
+                .filter(|host| lineages.root_of(host.species.name) == partner)
+                .max_by_key(|host| (host.biomass.total(), std::cmp::Reverse(host.id)))
+                .map(|host| host.id);
+            let Some(host_id) = host else {
+                continue;
+            };
+
+            let mut taken = Vec::new();
+            if let Some(host) = ecosystem.creatures.get_mut(host_id) {
+                let mut remaining = amount.min(host.biomass.total() - 1);
+                for matter_type in [MatterType::PlantMatter, MatterType::AnimalMatter] {
+                    let removed = host.biomass.remove_matter(matter_type, remaining);
+                    remaining -= removed;
+                    if removed > 0 {
+                        taken.push((matter_type, removed));
+                    }
+                }
+            }
+
+            let Some(parasite) = ecosystem.creatures.get_mut(parasite_id) else {
+                continue;
+            };
+            let built_type = Self::biomass_type(&parasite.species);
+            for (matter_type, removed) in &taken {
+                for (gained_type, gained) in parasite.build_biomass(built_type, *removed) {
+                    if gained > 0 && *matter_type != gained_type {
+                        matter_transformed += 1;
+                        events.push(LifecycleEvent::MatterTransformed {
+                            from_type: *matter_type,
+                            to_type: gained_type,
+                            amount: gained,
+                        });
+                    }
+                }
+            }
+
+            let amount = taken.iter().map(|(_, removed)| removed).sum();
+            events.push(LifecycleEvent::Parasitized { parasite_id, host_id, amount });
+        }
+
+        Ok(PhaseResult {
+            creatures_processed,
+            matter_transformed,
+            events,
+        })
+    }
+
+    fn validate_preconditions(&self, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+        if !INVARIANT_CHECKS_ENABLED {
+            return Ok(());
+        }
+
+        invariants::check_no_dead_creatures(self.phase_name(), ecosystem)?;
+        invariants::check_population_invariants(self.phase_name(), ecosystem)
+    }
+
+    fn validate_postconditions(&self, ecosystem: &EcosystemPopulation) -> Result<(), PhaseError> {
+        // Parasitism moves biomass but never kills a host outright
+        invariants::check_population_invariants(self.phase_name(), ecosystem)
+    }
+
+    fn phase_name(&self) -> &'static str {
+        "Symbiosis"
+    }
+}
+
+impl Default for SymbiosisPhase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::species::{get_species, InteractionKind, Kingdom, SpeciesColor};
+
+    /// A pollinator Clover can't set seed without, and a tick that feeds on Rabbits
+    fn partners() -> (Species, Species) {
+        let pollinator = Species::new("Pollinator", Kingdom::Animal, 1, 6, SpeciesColor::srgb(0.9, 0.8, 0.2))
+            .with_relationship("Clover", InteractionKind::Mutualism, RelationshipEffect::RequiredForReproduction);
+        let tick = Species::new("Tick", Kingdom::Animal, 1, 6, SpeciesColor::srgb(0.3, 0.2, 0.2))
+            .with_relationship("Rabbit", InteractionKind::Parasitism, RelationshipEffect::TakesBiomass { amount: 3 });
+        (pollinator, tick)
+    }
+
+    #[test]
+    fn a_required_partner_holds_back_breeding_before_it_is_played() {
+        let (pollinator, _) = partners();
+        let mut ecosystem = EcosystemPopulation { symbiosis: Symbiosis::from_species([&pollinator]), ..Default::default() };
+        let (near, far) = (TilePosition::new(0, 0), TilePosition::new(2, 2));
+        ecosystem.add_mature_creature_at(get_species("Clover").unwrap().clone(), (5, 0), near);
+        ecosystem.add_mature_creature_at(get_species("Clover").unwrap().clone(), (5, 0), far);
+
+        let map = SymbiosisMap::build(&ecosystem);
+        assert!(!map.can_breed("Clover", near));
+        assert!(!map.can_breed("Clover", far));
+
+        ecosystem.add_mature_creature_at(pollinator, (0, 1), near);
+        let map = SymbiosisMap::build(&ecosystem);
+        assert!(map.can_breed("Clover", near));
+        assert!(!map.can_breed("Clover", far));
+    }
+
+    #[test]
+    fn parasites_take_biomass_but_leave_their_host_some() {
+        let (_, tick) = partners();
+        let mut ecosystem = EcosystemPopulation::default();
+        let rabbit = ecosystem.add_mature_creature(get_species("Rabbit").unwrap().clone(), (0, 2));
+        let tick = ecosystem.add_mature_creature(tick, (0, 1));
+
+        let result = SymbiosisPhase::new().execute(&mut ecosystem).unwrap();
+
+        assert_eq!(ecosystem.creatures.get(rabbit).unwrap().biomass.total(), 1);
+        assert!(result.events.iter().any(|event| matches!(
+            event,
+            LifecycleEvent::Parasitized { parasite_id, host_id, amount: 1 } if *parasite_id == tick && *host_id == rabbit
+        )));
+    }
+}
@@ -1,10 +1,18 @@
 pub mod cards;
 pub mod game_state;
+pub mod event_log;
 pub mod species;
 pub mod lifecycle;
+#[cfg(feature = "bevy")]
+pub mod plugin;
 
 // Re-export specific items to avoid conflicts
-pub use cards::{Card, Deck, Hand, PlayCardEvent, DiscardCardEvent, handle_play_card_event, handle_discard_card_event};
+pub use cards::{Card, Deck, Hand, PlayCardEvent, DiscardCardEvent};
+#[cfg(feature = "bevy")]
+pub use cards::{handle_play_card_event, handle_discard_card_event};
 pub use game_state::GameState;
+pub use event_log::{EcosystemLog, LogEntry, LogEventKind};
 pub use species::{Species, Kingdom, BiomassConversion};
 pub use lifecycle::{EcosystemPopulation, IndividualCreature, DailySimulation};
+#[cfg(feature = "bevy")]
+pub use plugin::EdenGameplayPlugin;
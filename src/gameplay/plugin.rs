@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+use crate::gameplay::{EcosystemLog, GameState};
+use crate::gameplay::cards::{PlayCardEvent, DiscardCardEvent, handle_play_card_event, handle_discard_card_event};
+use crate::gameplay::lifecycle::{
+    AddSpeciesToEcosystemEvent, SimulateDayEvent, SimulationFailedEvent,
+    CreatureBornEvent, CreatureDiedEvent, CreatureMaturedEvent, CreatureFedEvent, MatterTransformedEvent, EcologicalEventOccurred, DayCompletedEvent,
+    EcosystemPopulation, DailySimulation, Climate, EcologicalEvents, Genetics, SpeciationRule, Disease,
+    handle_add_species_to_ecosystem_event, handle_simulate_day_event,
+};
+
+/// Cards, lifecycle simulation and their events
+/// Has no rendering or window dependencies, so an app built with `MinimalPlugins` plus this plugin
+/// can play full turns driven by injected `PlayCardEvent`/`DiscardCardEvent`/`SimulateDayEvent`s
+pub struct EdenGameplayPlugin;
+
+impl Plugin for EdenGameplayPlugin {
+    fn build(&self, app: &mut App) {
+        // Register events
+        app.add_event::<PlayCardEvent>();
+        app.add_event::<DiscardCardEvent>();
+        app.add_event::<AddSpeciesToEcosystemEvent>();
+        app.add_event::<SimulateDayEvent>();
+        app.add_event::<SimulationFailedEvent>();
+        app.add_event::<CreatureBornEvent>();
+        app.add_event::<CreatureDiedEvent>();
+        app.add_event::<CreatureMaturedEvent>();
+        app.add_event::<CreatureFedEvent>();
+        app.add_event::<MatterTransformedEvent>();
+        app.add_event::<EcologicalEventOccurred>();
+        app.add_event::<DayCompletedEvent>();
+
+        // Add resources
+        app.init_resource::<GameState>();
+        // Each game gets its own weather and random events
+        app.insert_resource(EcosystemPopulation {
+            climate: Climate::new(fastrand::u64(..)),
+            ecological_events: EcologicalEvents::new(fastrand::u64(..)),
+            genetics: Genetics::new(fastrand::u64(..)).with_speciation(SpeciationRule::default()),
+            disease: Disease::new(fastrand::u64(..)),
+            ..default()
+        });
+        app.init_resource::<DailySimulation>();
+        app.init_resource::<EcosystemLog>();
+
+        // Chained so a played or discarded card resolves within a single update
+        app.add_systems(Update, (
+            handle_play_card_event,
+            handle_discard_card_event,
+            handle_add_species_to_ecosystem_event,
+            handle_simulate_day_event,
+        ).chain());
+    }
+}
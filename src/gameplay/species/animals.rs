@@ -1,19 +1,21 @@
-use bevy::prelude::*;
-use crate::gameplay::lifecycle::MatterType;
-use super::{Kingdom, Species, BiomassConversion};
+use crate::gameplay::lifecycle::{MatterType, Season};
+use super::{Kingdom, Species, SpeciesColor, BiomassConversion};
 use std::collections::HashMap;
 
 pub fn get_animal_tier_1() -> HashMap<&'static str, Species> {
     let mut species = HashMap::new();
 
     species.insert("Rabbit", 
-        Species::new("Rabbit", Kingdom::Animal, 1, 6, Color::srgb(0.6, 0.5, 0.4))
+        Species::new("Rabbit", Kingdom::Animal, 1, 6, SpeciesColor::srgb(0.6, 0.5, 0.4))
             // Lifecycle system - Primary consumers (herbivores)
             .with_feeding_requirement(MatterType::PlantMatter, 2)
             .with_biomass_conversion(BiomassConversion::PlantToAnimal { efficiency: 0.7 })
+            .with_excretion(MatterType::DeadPlantMatter, 1) // Droppings
             .with_growth_age(14) // Rabbits mature quickly
             .with_reproduction_cooldown(20) // Fast breeding
             .with_lifespan(180, 360) // 6-12 months
+            .with_breeding_season(Season::Spring)
+            .with_breeding_season(Season::Summer)
     );
 
     species
@@ -23,13 +25,32 @@ pub fn get_animal_tier_2() -> HashMap<&'static str, Species> {
     let mut species = HashMap::new();
 
     species.insert("Frog", 
-        Species::new("Frog", Kingdom::Animal, 2, 4, Color::srgb(0.2, 0.6, 0.3))
+        Species::new("Frog", Kingdom::Animal, 2, 4, SpeciesColor::srgb(0.2, 0.6, 0.3))
             // Lifecycle system - Secondary consumers (carnivores)
             .with_feeding_requirement(MatterType::AnimalMatter, 2)
             .with_biomass_conversion(BiomassConversion::AnimalToAnimal { efficiency: 0.6 })
+            .with_excretion(MatterType::DeadAnimalMatter, 1)
+            .with_environmental_range(MatterType::GroundWater, 30, i32::MAX) // Amphibians need moisture
+            .with_reproduction_environment(MatterType::GroundWater, 40, i32::MAX) // Breeds in wet conditions
             .with_growth_age(25) // Longer development time
             .with_reproduction_cooldown(40) // Less frequent breeding
             .with_lifespan(240, 480) // 8-16 months
+            .with_dormancy(Season::Winter) // Hibernates
+            .with_breeding_season(Season::Spring) // Spawns in spring ponds
+    );
+
+    species.insert("Bird", 
+        Species::new("Bird", Kingdom::Animal, 4, 4, SpeciesColor::srgb(0.3, 0.5, 0.8))
+            // Lifecycle system - Omnivore, preferring insects, then berries, but getting by on plants
+            .with_feeding_requirement(MatterType::AnimalMatter, 2)
+            .with_biomass_conversion(BiomassConversion::AnimalToAnimal { efficiency: 0.6 })
+            .with_alternative_diet(&[(MatterType::Fruit, 2)], BiomassConversion::PlantToAnimal { efficiency: 0.7 })
+            .with_alternative_diet(&[(MatterType::PlantMatter, 3)], BiomassConversion::PlantToAnimal { efficiency: 0.5 })
+            .with_excretion(MatterType::DeadAnimalMatter, 1)
+            .with_growth_age(20)
+            .with_reproduction_cooldown(30)
+            .with_lifespan(300, 600) // 10-20 months
+            .with_breeding_season(Season::Spring) // Nests in spring
     );
 
     species
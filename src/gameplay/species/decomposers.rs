@@ -1,7 +1,6 @@
-use bevy::prelude::*;
-use super::{Kingdom, Species};
+use super::{Kingdom, Species, SpeciesColor};
 use super::lifecycle_config::*;
-use crate::gameplay::lifecycle::MatterType;
+use crate::gameplay::lifecycle::{MatterType, Season};
 use std::collections::HashMap;
 
 pub fn get_fungi_tier_1() -> HashMap<&'static str, Species> {
@@ -9,7 +8,7 @@ pub fn get_fungi_tier_1() -> HashMap<&'static str, Species> {
 
     // Add Mushrooms - key decomposer species for easy identification
     species.insert("Mushroom", 
-        Species::new("Mushroom", Kingdom::Fungi, 1, 5, Color::srgb(0.6, 0.4, 0.3))
+        Species::new("Mushroom", Kingdom::Fungi, 1, 5, SpeciesColor::srgb(0.6, 0.4, 0.3))
             .with_feeding_requirement(MatterType::DeadPlantMatter, 1)
             .with_feeding_requirement(MatterType::DeadAnimalMatter, 1)
             .with_biomass_conversion(BiomassConversion::Decomposition { 
@@ -17,9 +16,14 @@ pub fn get_fungi_tier_1() -> HashMap<&'static str, Species> {
                 biomass_gain: 0.4, // Better growth than rot fungi
                 matter_type: MatterType::PlantMatter
             })
+            .with_environmental_range(MatterType::GroundWater, 20, i32::MAX) // Fungi need damp soil
             .with_growth_age(12) // Moderate growth time
             .with_reproduction_cooldown(18) // Regular spore production
             .with_lifespan(45, 90) // Moderate lifespan
+            .with_breeding_season(Season::Autumn) // Fruits in the autumn damp
+            .with_breeding_season(Season::Spring)
+            // Mycorrhizal partnership: draws nutrients into nearby clover roots
+            .with_relationship("Clover", InteractionKind::Mutualism, RelationshipEffect::BoostsFeeding { bonus: 0.2 })
     );
 
     species
@@ -29,7 +33,7 @@ pub fn get_fungi_tier_2() -> HashMap<&'static str, Species> {
     let mut species = HashMap::new();
 
     species.insert("Earthworm", 
-        Species::new("Earthworm", Kingdom::Animal, 1, 8, Color::srgb(0.5, 0.3, 0.2))
+        Species::new("Earthworm", Kingdom::Animal, 1, 8, SpeciesColor::srgb(0.5, 0.3, 0.2))
             // Lifecycle system - Animal decomposer
             .with_feeding_requirement(MatterType::DeadPlantMatter, 2)
             .with_feeding_requirement(MatterType::DeadAnimalMatter, 1)
@@ -38,6 +42,8 @@ pub fn get_fungi_tier_2() -> HashMap<&'static str, Species> {
                 biomass_gain: 0.3, // Slow growth
                 matter_type: MatterType::AnimalMatter // Earthworms are animal matter
             })
+            .with_excretion(MatterType::SoilNutrients, 1) // Castings
+            .with_environmental_range(MatterType::GroundWater, 15, i32::MAX) // Dry soil stresses worms
             .with_growth_age(8) // Quick to mature
             .with_reproduction_cooldown(5) // Frequent reproduction
             .with_lifespan(60, 120) // 2-4 months
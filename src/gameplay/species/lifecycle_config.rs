@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use crate::gameplay::lifecycle::MatterType;
+use crate::gameplay::lifecycle::{MatterType, MaturityStage, Season};
 
 /// Requirements for growth phase
 #[derive(Clone, Debug)]
@@ -23,6 +23,35 @@ pub struct FeedingRequirements {
     pub minimum_threshold: f32,
     /// What matter type this species converts input to when feeding
     pub biomass_conversion: BiomassConversion,
+    /// Feeding priority within a trophic level when food is scarce (higher feeds first)
+    pub priority: i32,
+    /// Fallback diets in order of preference, eaten when the base requirements run short
+    pub alternative_diets: Vec<DietAlternative>,
+    /// Waste left on the creature's tile each day it eats, per creature, made from the part of its meals it doesn't keep
+    pub excretion: Vec<(MatterType, u32)>,
+}
+
+/// A fallback food source for a species
+/// Its amounts stand in for the whole preferred diet, so they also set the substitution ratio
+/// (e.g. 3 plant matter in place of 2 animal matter)
+#[derive(Clone, Debug)]
+pub struct DietAlternative {
+    pub requirements: HashMap<MatterType, u32>,
+    /// How this food converts to biomass
+    pub conversion: BiomassConversion,
+}
+
+impl FeedingRequirements {
+    /// Every diet in order of preference as (requirements, conversion), starting with the base requirements
+    pub fn diets(&self) -> impl Iterator<Item = (&HashMap<MatterType, u32>, &BiomassConversion)> {
+        std::iter::once((&self.base_requirements, &self.biomass_conversion))
+            .chain(self.alternative_diets.iter().map(|diet| (&diet.requirements, &diet.conversion)))
+    }
+
+    /// A diet by preference (0 = the base requirements)
+    pub fn diet(&self, preference: usize) -> Option<(&HashMap<MatterType, u32>, &BiomassConversion)> {
+        self.diets().nth(preference)
+    }
 }
 
 /// How feeding input converts to creature biomass
@@ -59,6 +88,8 @@ pub struct ReproductionRequirements {
     pub reproduction_probability: f32,
     /// Days that must pass between reproduction attempts
     pub cooldown_days: u32,
+    /// Biomass a plant sets aside as fruit on its tile each time it breeds (0 = doesn't fruit)
+    pub fruit_set: u32,
 }
 
 /// Population requirements for reproduction
@@ -79,6 +110,138 @@ pub struct MortalityFactors {
     pub starvation_tolerance: u32,
     /// Days in bad environmental conditions before death
     pub environmental_tolerance: u32,
+    /// How readily the species catches disease (0.0 = never, 1.0 = fully susceptible)
+    pub disease_susceptibility: f32,
+}
+
+/// How a species responds to the seasons
+#[derive(Clone, Debug, Default)]
+pub struct SeasonalBehavior {
+    /// Seasons spent dormant: no feeding, growth or breeding, but no hunger or environmental stress either
+    pub dormant_seasons: Vec<Season>,
+    /// Seasons in which the species can breed (empty = all year)
+    pub breeding_seasons: Vec<Season>,
+}
+
+impl SeasonalBehavior {
+    pub fn is_dormant_in(&self, season: Season) -> bool {
+        self.dormant_seasons.contains(&season)
+    }
+
+    pub fn can_breed_in(&self, season: Season) -> bool {
+        self.breeding_seasons.is_empty() || self.breeding_seasons.contains(&season)
+    }
+}
+
+/// How a creature behaves while in one life stage
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StageTraits {
+    /// Scales how much the creature eats; stages that don't feed (0.0) rest without growing hungry or stressed
+    pub feeding_multiplier: f32,
+    /// Scales how much biomass the creature keeps from what it eats
+    pub feeding_efficiency: f32,
+    /// Scales how quickly hunger and disease kill the creature (1.0 = normal)
+    pub vulnerability: f32,
+    pub can_reproduce: bool,
+}
+
+impl StageTraits {
+    /// How a stage behaves unless the species says otherwise
+    pub fn default_for(stage: MaturityStage) -> Self {
+        match stage {
+            MaturityStage::Seed => Self { feeding_multiplier: 0.0, feeding_efficiency: 1.0, vulnerability: 0.5, can_reproduce: false },
+            MaturityStage::Juvenile | MaturityStage::Dead => Self { feeding_multiplier: 1.0, feeding_efficiency: 1.0, vulnerability: 1.0, can_reproduce: false },
+            MaturityStage::Mature => Self { feeding_multiplier: 1.0, feeding_efficiency: 1.0, vulnerability: 1.0, can_reproduce: true },
+            MaturityStage::Senescent => Self { feeding_multiplier: 1.0, feeding_efficiency: 0.6, vulnerability: 1.5, can_reproduce: false },
+        }
+    }
+}
+
+/// What moves a creature on from one life stage to the next
+#[derive(Clone, Debug, PartialEq)]
+pub enum StageTrigger {
+    /// Fed to at least this fraction of its needs while awake and within the species' growth ranges,
+    /// once it has reached the species' growth age (`GrowthRequirements::minimum_age`)
+    WellFed { threshold: f32 },
+    /// At least this many days old with its tile within the species' growth ranges, even while resting
+    Germinates { min_days: u32 },
+    /// Reached this age in days, stretched or shortened by the creature's lifespan trait
+    Age { days: u32 },
+}
+
+/// One edge of a species' stage graph
+#[derive(Clone, Debug)]
+pub struct StageTransition {
+    pub from: MaturityStage,
+    pub to: MaturityStage,
+    pub trigger: StageTrigger,
+}
+
+/// The life stages a species passes through, how each behaves and what moves it on
+/// Dormancy isn't a stage: a creature in any stage can go dormant and wakes up in the stage it left
+#[derive(Clone, Debug)]
+pub struct LifeStages {
+    /// Stage offspring and newly introduced creatures start in
+    pub birth_stage: MaturityStage,
+    /// Stages that behave differently from `StageTraits::default_for`
+    pub traits: Vec<(MaturityStage, StageTraits)>,
+    /// Transitions in order of precedence; the first one met is taken
+    pub transitions: Vec<StageTransition>,
+    /// Days outside its growth ranges after which a creature goes dormant until conditions recover (None = never)
+    pub stress_dormancy_days: Option<u32>,
+}
+
+impl LifeStages {
+    pub fn traits(&self, stage: MaturityStage) -> StageTraits {
+        self.traits
+            .iter()
+            .find(|(traits_stage, _)| *traits_stage == stage)
+            .map_or(StageTraits::default_for(stage), |(_, traits)| *traits)
+    }
+
+    pub fn transitions_from(&self, stage: MaturityStage) -> impl Iterator<Item = &StageTransition> {
+        self.transitions.iter().filter(move |transition| transition.from == stage)
+    }
+}
+
+/// The nature of a relationship between two species
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InteractionKind {
+    /// Both species gain (usually declared on each side of the pair)
+    Mutualism,
+    /// The partner gains and this species is unaffected
+    Commensalism,
+    /// This species gains at the partner's (its host's) expense
+    Parasitism,
+}
+
+impl InteractionKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            InteractionKind::Mutualism => "Mutualism",
+            InteractionKind::Commensalism => "Commensalism",
+            InteractionKind::Parasitism => "Parasitism",
+        }
+    }
+}
+
+/// What a species does for, or to, a partner living on the same or a neighbouring tile
+#[derive(Clone, Debug, PartialEq)]
+pub enum RelationshipEffect {
+    /// Raises the partner's feeding efficiency by this fraction (0.2 = +20%), never past 100% of a meal
+    BoostsFeeding { bonus: f32 },
+    /// The partner can only breed with this species nearby, once it is known to the garden
+    RequiredForReproduction,
+    /// Takes this much biomass from one nearby partner each day
+    TakesBiomass { amount: u32 },
+}
+
+/// A relationship a species has with one partner species (variants of the partner included)
+#[derive(Clone, Debug)]
+pub struct SpeciesRelationship {
+    pub partner: &'static str,
+    pub kind: InteractionKind,
+    pub effect: RelationshipEffect,
 }
 
 /// What biomass type this species consists of
@@ -92,6 +255,23 @@ pub enum BiomassComposition {
     Mixed { plant_ratio: f32, animal_ratio: f32 },
 }
 
+impl BiomassComposition {
+    /// Divide an amount of biomass between this composition's components, as (plant_matter, animal_matter)
+    /// Mixed compositions split by their ratios, rounding so that none of the amount is lost
+    pub fn split(&self, amount: u32) -> (u32, u32) {
+        match self {
+            BiomassComposition::Plant => (amount, 0),
+            BiomassComposition::Animal => (0, amount),
+            BiomassComposition::Mixed { plant_ratio, animal_ratio } => {
+                let total_ratio = plant_ratio + animal_ratio;
+                let plant_share = if total_ratio > 0.0 { plant_ratio / total_ratio } else { 0.5 };
+                let plant_amount = ((amount as f32 * plant_share).round() as u32).min(amount);
+                (plant_amount, amount - plant_amount)
+            }
+        }
+    }
+}
+
 impl Default for FeedingRequirements {
     fn default() -> Self {
         Self {
@@ -99,6 +279,9 @@ impl Default for FeedingRequirements {
             maturity_multiplier: 1.0,
             minimum_threshold: 0.5,
             biomass_conversion: BiomassConversion::PlantGrowth { efficiency: 1.0 },
+            priority: 0,
+            alternative_diets: Vec::new(),
+            excretion: Vec::new(),
         }
     }
 }
@@ -113,6 +296,21 @@ impl Default for GrowthRequirements {
     }
 }
 
+impl Default for LifeStages {
+    fn default() -> Self {
+        Self {
+            birth_stage: MaturityStage::Juvenile,
+            traits: Vec::new(),
+            transitions: vec![StageTransition {
+                from: MaturityStage::Juvenile,
+                to: MaturityStage::Mature,
+                trigger: StageTrigger::WellFed { threshold: 0.7 },
+            }],
+            stress_dormancy_days: None,
+        }
+    }
+}
+
 impl Default for ReproductionRequirements {
     fn default() -> Self {
         Self {
@@ -123,6 +321,7 @@ impl Default for ReproductionRequirements {
             offspring_biomass: 1,
             reproduction_probability: 0.1,
             cooldown_days: 7,
+            fruit_set: 0,
         }
     }
 }
@@ -133,6 +332,7 @@ impl Default for MortalityFactors {
             natural_lifespan: (30, 60),
             starvation_tolerance: 3,
             environmental_tolerance: 10,
+            disease_susceptibility: 1.0,
         }
     }
 }
@@ -1,19 +1,23 @@
-use bevy::prelude::*;
-use crate::gameplay::lifecycle::MatterType;
-use super::{Kingdom, Species, BiomassConversion};
+use crate::gameplay::lifecycle::{MatterType, Season};
+use super::{Kingdom, Species, SpeciesColor, BiomassConversion, InteractionKind, RelationshipEffect};
 use std::collections::HashMap;
 
 pub fn get_plant_tier_1() -> HashMap<&'static str, Species> {
     let mut species = HashMap::new();
 
     species.insert("Clover", 
-        Species::new("Clover", Kingdom::Plant, 2, 6, Color::srgb(0.2, 0.8, 0.4))
+        Species::new("Clover", Kingdom::Plant, 2, 6, SpeciesColor::srgb(0.2, 0.8, 0.4))
             // Lifecycle system - Nitrogen-fixing producer
             .with_feeding_requirement(MatterType::SoilNutrients, 1) // Needs some base nutrients
             .with_biomass_conversion(BiomassConversion::PlantGrowth { efficiency: 0.9 })
+            .with_environmental_range(MatterType::Sunlight, 40, i32::MAX) // Needs moderate light
+            .with_reproduction_environment(MatterType::GroundWater, 20, i32::MAX)
             .with_growth_age(7) // Moderate growth time
             .with_reproduction_cooldown(15) // Regular reproduction
             .with_lifespan(90, 180) // Seasonal plant
+            .with_dormancy(Season::Winter) // Dies back over winter
+            // Feeds sugars to the fungi threaded through its roots
+            .with_relationship("Mushroom", InteractionKind::Mutualism, RelationshipEffect::BoostsFeeding { bonus: 0.2 })
     );
 
     species
@@ -23,13 +27,18 @@ pub fn get_plant_tier_2() -> HashMap<&'static str, Species> {
     let mut species = HashMap::new();
 
     species.insert("Berry Bush", 
-        Species::new("Berry Bush", Kingdom::Plant, 2, 6, Color::srgb(0.6, 0.3, 0.7))
+        Species::new("Berry Bush", Kingdom::Plant, 2, 6, SpeciesColor::srgb(0.6, 0.3, 0.7))
             // Lifecycle system - Advanced plant producer
             .with_feeding_requirement(MatterType::SoilNutrients, 2)
             .with_biomass_conversion(BiomassConversion::PlantGrowth { efficiency: 1.0 })
+            .with_environmental_range(MatterType::Sunlight, 60, i32::MAX) // Needs strong light
+            .with_environmental_range(MatterType::GroundWater, 20, i32::MAX)
             .with_growth_age(12) // Takes longer to establish
             .with_reproduction_cooldown(20) // Less frequent reproduction
             .with_lifespan(120, 240) // Longer-lived than grass
+            .with_dormancy(Season::Winter)
+            .with_breeding_season(Season::Summer) // Fruits in summer
+            .with_fruiting(6) // A summer crop of berries feeds birds, and the ones left uneaten grow into new bushes
     );
 
     species
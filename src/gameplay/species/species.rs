@@ -1,4 +1,3 @@
-use bevy::prelude::*;
 use crate::gameplay::species::plants::{get_all_plant_species};
 use crate::gameplay::species::animals::{get_all_animal_species};
 use crate::gameplay::species::decomposers::{get_all_fungi_species};
@@ -23,13 +22,34 @@ impl Kingdom {
     }
 }
 
+/// Display color for a species, kept free of Bevy so the simulation core doesn't depend on rendering
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpeciesColor {
+    pub red: f32,
+    pub green: f32,
+    pub blue: f32,
+}
+
+impl SpeciesColor {
+    pub const fn srgb(red: f32, green: f32, blue: f32) -> Self {
+        Self { red, green, blue }
+    }
+}
+
+#[cfg(feature = "game")]
+impl From<SpeciesColor> for bevy::prelude::Color {
+    fn from(color: SpeciesColor) -> Self {
+        bevy::prelude::Color::srgb(color.red, color.green, color.blue)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Species {
     pub name: &'static str,
     pub kingdom: Kingdom,
     pub unlock_round: u32,
     pub max_population: u32,
-    pub color: Color,
+    pub color: SpeciesColor,
     
     // Lifecycle fields
     pub feeding_requirements: FeedingRequirements,
@@ -37,6 +57,9 @@ pub struct Species {
     pub reproduction_requirements: ReproductionRequirements,
     pub mortality_factors: MortalityFactors,
     pub biomass_composition: BiomassComposition,
+    pub seasonal_behavior: SeasonalBehavior,
+    pub life_stages: LifeStages,
+    pub relationships: Vec<SpeciesRelationship>,
 }
 
 impl Species {
@@ -45,7 +68,7 @@ impl Species {
         kingdom: Kingdom,
         unlock_round: u32,
         max_population: u32,
-        color: Color,
+        color: SpeciesColor,
     ) -> Self {
         Self {
             name,
@@ -62,6 +85,9 @@ impl Species {
                 Kingdom::Plant | Kingdom::Fungi => BiomassComposition::Plant,
                 Kingdom::Animal => BiomassComposition::Animal,
             },
+            seasonal_behavior: SeasonalBehavior::default(),
+            life_stages: LifeStages::default(),
+            relationships: Vec::new(),
         }
     }
 
@@ -76,6 +102,26 @@ impl Species {
         self
     }
 
+    /// Add a fallback diet, eaten (in the order added) when the preferred food runs short
+    pub fn with_alternative_diet(mut self, requirements: &[(crate::gameplay::lifecycle::MatterType, u32)], conversion: BiomassConversion) -> Self {
+        self.feeding_requirements.alternative_diets.push(DietAlternative {
+            requirements: requirements.iter().copied().collect(),
+            conversion,
+        });
+        self
+    }
+
+    /// Leave this much waste behind each day the creature eats, out of what it doesn't turn into biomass
+    pub fn with_excretion(mut self, matter_type: crate::gameplay::lifecycle::MatterType, amount: u32) -> Self {
+        self.feeding_requirements.excretion.push((matter_type, amount));
+        self
+    }
+
+    pub fn with_feeding_priority(mut self, priority: i32) -> Self {
+        self.feeding_requirements.priority = priority;
+        self
+    }
+
     pub fn with_growth_age(mut self, minimum_age: u32) -> Self {
         self.growth_requirements.minimum_age = minimum_age;
         self
@@ -86,10 +132,96 @@ impl Species {
         self
     }
 
+    /// Set aside this much biomass as fruit each time the plant breeds
+    /// Fruit can be eaten without harming the plant, and what's left uneaten is sown as seedlings when it next breeds
+    pub fn with_fruiting(mut self, amount: u32) -> Self {
+        self.reproduction_requirements.fruit_set = amount;
+        self
+    }
+
+    pub fn with_environmental_range(mut self, matter_type: crate::gameplay::lifecycle::MatterType, min: i32, max: i32) -> Self {
+        self.growth_requirements.environmental_factors.insert(matter_type, (min, max));
+        self
+    }
+
+    pub fn with_reproduction_environment(mut self, matter_type: crate::gameplay::lifecycle::MatterType, min: i32, max: i32) -> Self {
+        self.reproduction_requirements.environmental_requirements.insert(matter_type, (min, max));
+        self
+    }
+
+    pub fn with_environmental_tolerance(mut self, days: u32) -> Self {
+        self.mortality_factors.environmental_tolerance = days;
+        self
+    }
+
+    pub fn with_disease_susceptibility(mut self, susceptibility: f32) -> Self {
+        self.mortality_factors.disease_susceptibility = susceptibility.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn with_dormancy(mut self, season: crate::gameplay::lifecycle::Season) -> Self {
+        self.seasonal_behavior.dormant_seasons.push(season);
+        self
+    }
+
+    pub fn with_breeding_season(mut self, season: crate::gameplay::lifecycle::Season) -> Self {
+        self.seasonal_behavior.breeding_seasons.push(season);
+        self
+    }
+
+    /// Start life as a seed (or egg) that rests until it is `min_days` old and its tile suits the species
+    pub fn with_seed_stage(mut self, min_days: u32) -> Self {
+        self.life_stages.birth_stage = crate::gameplay::lifecycle::MaturityStage::Seed;
+        self.life_stages.transitions.push(StageTransition {
+            from: crate::gameplay::lifecycle::MaturityStage::Seed,
+            to: crate::gameplay::lifecycle::MaturityStage::Juvenile,
+            trigger: StageTrigger::Germinates { min_days },
+        });
+        self
+    }
+
+    /// Grow old at this age: senescent adults stop breeding and feed less efficiently
+    pub fn with_senescence(mut self, age_days: u32) -> Self {
+        self.life_stages.transitions.push(StageTransition {
+            from: crate::gameplay::lifecycle::MaturityStage::Mature,
+            to: crate::gameplay::lifecycle::MaturityStage::Senescent,
+            trigger: StageTrigger::Age { days: age_days },
+        });
+        self
+    }
+
+    pub fn with_stage_traits(mut self, stage: crate::gameplay::lifecycle::MaturityStage, traits: StageTraits) -> Self {
+        self.life_stages.traits.retain(|(existing, _)| *existing != stage);
+        self.life_stages.traits.push((stage, traits));
+        self
+    }
+
+    /// Go dormant after this many days outside the growth ranges, waking once conditions recover
+    pub fn with_stress_dormancy(mut self, days: u32) -> Self {
+        self.life_stages.stress_dormancy_days = Some(days);
+        self
+    }
+
     pub fn with_lifespan(mut self, min_days: u32, max_days: u32) -> Self {
         self.mortality_factors.natural_lifespan = (min_days, max_days);
         self
     }
+
+    /// Declare how this species affects a partner species living nearby
+    pub fn with_relationship(mut self, partner: &'static str, kind: InteractionKind, effect: RelationshipEffect) -> Self {
+        self.relationships.push(SpeciesRelationship { partner, kind, effect });
+        self
+    }
+
+    /// Biomass a newly introduced creature of this species starts with, as (plant_matter, animal_matter)
+    pub fn starting_biomass(&self) -> (u32, u32) {
+        match self.biomass_composition {
+            BiomassComposition::Plant => (3, 0), // Start with 3 plant matter
+            BiomassComposition::Animal => (0, 2), // Start with 2 animal matter
+            // For mixed compositions, split the total biomass proportionally
+            BiomassComposition::Mixed { .. } => self.biomass_composition.split(3),
+        }
+    }
 }
 
 
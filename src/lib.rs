@@ -1,4 +1,5 @@
 pub mod gameplay;
+#[cfg(feature = "game")]
 pub mod visualization;
 // Temporarily disable problematic modules until they're fixed
 #[cfg(feature = "svg_rendering")]
@@ -8,13 +9,15 @@ pub mod creatures;
 #[cfg(feature = "svg_rendering")]
 pub mod rendering;
 
+#[cfg(feature = "bevy")]
 use bevy::prelude::*;
-use gameplay::lifecycle::{SimulateDayEvent, AddSpeciesToEcosystemEvent, handle_add_species_to_ecosystem_event, handle_simulate_day_event};
-use gameplay::cards::{PlayCardEvent, DiscardCardEvent, handle_play_card_event, handle_discard_card_event};
-use visualization::init_ui_elements;
-use visualization::*;
+#[cfg(feature = "bevy")]
+use gameplay::EdenGameplayPlugin;
+#[cfg(feature = "game")]
+use visualization::EdenUiPlugin;
 
 /// Creates the main Bevy app with shared configuration for native and web builds
+#[cfg(feature = "game")]
 pub fn create_app(window_config: Window) -> App {
     let mut app = App::new();
     
@@ -28,49 +31,22 @@ pub fn create_app(window_config: Window) -> App {
     // app.add_plugins(plugins::svg_rendering_plugin::SvgRenderingPlugin);
     // app.add_plugins(plugins::visualization_plugin::CreatureVisualizationPlugin);
     
-    // Register events
-    app.add_event::<PlayCardEvent>();
-    app.add_event::<DiscardCardEvent>();
-    app.add_event::<AddSpeciesToEcosystemEvent>();
-    app.add_event::<SimulateDayEvent>();
-    
-    // Add resources
-    app.init_resource::<gameplay::GameState>();
-    app.init_resource::<gameplay::lifecycle::EcosystemPopulation>();
-    app.init_resource::<visualization::display::ScreenLayout>();
-    app.init_resource::<SelectedCard>();
-    
-    // Add startup systems
-    app.add_systems(Startup, (
-        init_ui_elements,
-        init_screen_layout,
-    ).chain());
-    
-    // Add update systems
-    app.add_systems(Update, (
-        // UI Systems
-        handle_window_resize,
-        handle_card_clicks,
-        handle_button_clicks,
-        update_button_visuals,
-        update_button_layout,
-        update_resource_display,
-        update_species_display,
-        update_hand_ui,
-        update_hand_layout,
-        update_card_visuals,
-        clear_selection_after_actions,
-        // Core Game Systems
-        handle_play_card_event,
-        handle_discard_card_event,
-        handle_add_species_to_ecosystem_event,
-        handle_simulate_day_event,
-    ));
+    app.add_plugins((EdenGameplayPlugin, EdenUiPlugin));
     
     app
 }
 
+/// Creates a windowless app running only the gameplay simulation
+/// Turns are driven by writing `PlayCardEvent`/`DiscardCardEvent`/`SimulateDayEvent`s and calling `app.update()`
+#[cfg(feature = "bevy")]
+pub fn create_headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, EdenGameplayPlugin));
+    app
+}
+
 // Native window configuration
+#[cfg(feature = "game")]
 pub fn native_window_config() -> Window {
     Window {
         title: "Eden2 - Ecosystem Card Game".to_string(),
@@ -80,7 +56,7 @@ pub fn native_window_config() -> Window {
 }
 
 // Web window configuration
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "game", target_arch = "wasm32"))]
 pub fn web_window_config() -> Window {
     Window {
         title: "Eden2 - Ecosystem Card Game".to_string(),
@@ -91,10 +67,10 @@ pub fn web_window_config() -> Window {
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "game", target_arch = "wasm32"))]
 use wasm_bindgen::prelude::*;
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "game", target_arch = "wasm32"))]
 #[wasm_bindgen(start)]
 pub fn main() {
     console_error_panic_hook::set_once();
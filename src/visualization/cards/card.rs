@@ -15,7 +15,7 @@ pub struct CardDefinition {
 pub fn get_card_definition(name: &str) -> Option<CardDefinition> {
     get_species(name).map(|species_def| CardDefinition {
         name: species_def.name,
-        color: species_def.color,
+        color: species_def.color.into(),
     })
 }
 
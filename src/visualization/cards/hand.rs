@@ -23,7 +23,7 @@ pub fn init_hand_cards(commands: &mut Commands, game_state: &GameState, screen_l
         // Spawn card background (green rectangle)
         let card_entity = commands.spawn((
             Sprite {
-                color: get_species(card.name()).expect("Species definition not found").color,
+                color: get_species(card.name()).expect("Species definition not found").color.into(),
                 custom_size: Some(card_size),
                 ..default()
             },
@@ -1,9 +1,10 @@
 use bevy::prelude::*;
 use bevy::window::{WindowResized, PrimaryWindow};
 use crate::gameplay::GameState;
-use crate::visualization::garden::{GardenBackground, LayoutInitialized, init_garden_ui, ResourceDisplayText, SpeciesDisplayText};
+use crate::gameplay::lifecycle::EcosystemPopulation;
+use crate::visualization::garden::{GardenBackground, LayoutInitialized, init_garden_ui, init_garden_tiles, ResourceDisplayText, SpeciesDisplayText};
 use crate::visualization::cards::init_hand_cards;
-use crate::visualization::ui::init_action_buttons;
+use crate::visualization::ui::{init_action_buttons, init_event_log_panel, init_notification_banner};
 use super::responsive_size_utils::{ResponsiveSize, FontSizeClass, PaddingClass, SpacingClass, ResponsiveExt};
 
 /// Screen layout manager for responsive UI positioning
@@ -137,6 +138,49 @@ impl ScreenLayout {
         )
     }
     
+    pub fn event_log_panel_size(&self) -> Vec2 {
+        Vec2::new(self.garden_area.x * 0.9, self.garden_area.y * 0.35)
+    }
+    
+    pub fn event_log_panel_center(&self) -> Vec2 {
+        let panel_size = self.event_log_panel_size();
+        Vec2::new(
+            self.garden_center.x,
+            self.garden_center.y - self.garden_area.y / 2.0 + panel_size.y / 2.0 + self.pad(PaddingClass::Medium), // Lower area of garden
+        )
+    }
+    
+    /// Part of the garden above the event log panel where the tile grid is drawn
+    pub fn garden_tiles_area(&self) -> (Vec2, Vec2) {
+        let padding = self.pad(PaddingClass::Medium);
+        let log_top = self.event_log_panel_center().y + self.event_log_panel_size().y / 2.0;
+        let garden_top = self.garden_center.y + self.garden_area.y / 2.0;
+        let size = Vec2::new(self.event_log_panel_size().x, (garden_top - log_top - padding * 2.0).max(0.0));
+        (Vec2::new(self.garden_center.x, log_top + padding + size.y / 2.0), size)
+    }
+    
+    /// Center and size of a garden tile in a grid of `grid_width` by `grid_height` tiles
+    pub fn garden_tile_geometry(&self, x: u32, y: u32, grid_width: u32, grid_height: u32) -> (Vec2, Vec2) {
+        let (area_center, area_size) = self.garden_tiles_area();
+        let tile_size = Vec2::new(area_size.x / grid_width as f32, area_size.y / grid_height as f32);
+        let top_left = area_center + Vec2::new(-area_size.x / 2.0, area_size.y / 2.0);
+        let center = top_left + Vec2::new((x as f32 + 0.5) * tile_size.x, -(y as f32 + 0.5) * tile_size.y);
+        (center, tile_size)
+    }
+    
+    /// Tile (x, y) under a world position, if it falls inside the garden tile grid
+    pub fn garden_tile_at(&self, world_pos: Vec2, grid_width: u32, grid_height: u32) -> Option<(u32, u32)> {
+        let (area_center, area_size) = self.garden_tiles_area();
+        let offset = world_pos - (area_center + Vec2::new(-area_size.x / 2.0, area_size.y / 2.0));
+        if offset.x < 0.0 || offset.y > 0.0 || offset.x >= area_size.x || -offset.y >= area_size.y {
+            return None;
+        }
+
+        let x = (offset.x / area_size.x * grid_width as f32) as u32;
+        let y = (-offset.y / area_size.y * grid_height as f32) as u32;
+        Some((x.min(grid_width - 1), y.min(grid_height - 1)))
+    }
+    
     // Helper methods for common UI measurements
     pub fn text_font_size(&self, class: FontSizeClass) -> f32 {
         self.responsive.font_size(class)
@@ -232,6 +276,7 @@ pub fn handle_window_resize(
 pub fn init_ui_elements(
     mut commands: Commands,
     game_state: Res<GameState>,
+    ecosystem: Res<EcosystemPopulation>,
     screen_layout: Res<ScreenLayout>,
 ) {
     commands.spawn(Camera2d);
@@ -239,8 +284,11 @@ pub fn init_ui_elements(
     commands.insert_resource(LayoutInitialized::default());
     
     init_garden_ui(&mut commands, &screen_layout);
+    init_garden_tiles(&mut commands, &screen_layout, &ecosystem.grid);
     init_hand_cards(&mut commands, &game_state, &screen_layout);
     init_action_buttons(&mut commands, &screen_layout);
+    init_event_log_panel(&mut commands, &screen_layout);
+    init_notification_banner(&mut commands, &screen_layout);
 }
 
 
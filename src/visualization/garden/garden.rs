@@ -1,6 +1,7 @@
 use bevy::prelude::*;
-use crate::gameplay::lifecycle::{EcosystemPopulation, MatterType};
+use crate::gameplay::lifecycle::{DeathCause, EcosystemPopulation, GardenGrid, MatterType, TilePosition, DAYS_PER_SEASON};
 use crate::visualization::display::{ScreenLayout};
+use crate::visualization::ui::{PlacementTargeting, targeted_species};
 use super::super::display::responsive_size_utils::FontSizeClass;
 
 /// UI Component marker for garden background
@@ -15,6 +16,24 @@ pub struct ResourceDisplayText;
 #[derive(Component)]
 pub struct SpeciesDisplayText;
 
+/// UI Component for one tile of the garden grid
+#[derive(Component)]
+pub struct GardenTile {
+    pub position: TilePosition,
+}
+
+/// UI Component for the population and resource label of a garden tile
+#[derive(Component)]
+pub struct GardenTileLabel {
+    pub position: TilePosition,
+}
+
+type TileSpriteQuery<'w, 's> = Query<'w, 's, (&'static GardenTile, &'static mut Transform, &'static mut Sprite)>;
+type TileLabelQuery<'w, 's> = Query<'w, 's, (&'static GardenTileLabel, &'static mut Text2d, &'static mut Transform, &'static mut TextFont), Without<GardenTile>>;
+
+/// Number of upcoming days shown in the weather forecast
+const FORECAST_DAYS: u32 = 3;
+
 /// Resource flag to track if layout has been initialized to actual window size
 #[derive(Resource, Default)]
 pub struct LayoutInitialized(pub bool);
@@ -57,17 +76,113 @@ pub fn init_garden_ui(commands: &mut Commands, screen_layout: &ScreenLayout) ->
     garden_background_entity
 }
 
+/// Spawn a sprite and label for every tile of the garden grid
+pub fn init_garden_tiles(commands: &mut Commands, screen_layout: &ScreenLayout, grid: &GardenGrid) {
+    for index in 0..grid.tile_count() {
+        let position = grid.position_of(index);
+        let (center, size) = screen_layout.garden_tile_geometry(position.x, position.y, grid.width(), grid.height());
+
+        commands.spawn((
+            Sprite {
+                color: tile_color(0.0),
+                custom_size: Some(size * 0.95),
+                ..default()
+            },
+            Transform::from_translation(center.extend(1.0)),
+            GardenTile { position },
+        ));
+
+        commands.spawn((
+            Text2d::new(""),
+            TextFont {
+                font_size: screen_layout.text_font_size(FontSizeClass::Small),
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            TextLayout::new_with_justify(JustifyText::Center),
+            Transform::from_translation(center.extend(1.5)),
+            GardenTileLabel { position },
+        ));
+    }
+}
+
+/// Soil tint for a tile, from bare (0.0) to the richest tile in the garden (1.0)
+fn tile_color(richness: f32) -> Color {
+    Color::srgba(0.45 - 0.2 * richness, 0.3 + 0.3 * richness, 0.15, 0.45)
+}
+
+/// Placement hint for a tile: green where the species' needs are met locally, red where they aren't
+fn placement_hint_color(suitable: bool) -> Color {
+    if suitable {
+        Color::srgba(0.3, 0.8, 0.3, 0.55)
+    } else {
+        Color::srgba(0.8, 0.3, 0.3, 0.55)
+    }
+}
+
+/// Keep tile sprites laid out with the garden and show each tile's creatures, nutrients and water
+/// While a card is being placed, tiles are tinted with placement hints instead of soil richness
+pub fn update_garden_tiles(
+    ecosystem_state: Res<EcosystemPopulation>,
+    screen_layout: Res<ScreenLayout>,
+    targeting: Res<PlacementTargeting>,
+    game_state: Res<crate::gameplay::GameState>,
+    mut tile_query: TileSpriteQuery,
+    mut label_query: TileLabelQuery,
+) {
+    if !(ecosystem_state.is_changed() || screen_layout.is_changed() || targeting.is_changed()) {
+        return;
+    }
+
+    let placing = targeted_species(&targeting, &game_state);
+
+    let grid = &ecosystem_state.grid;
+    let population = ecosystem_state.population_by_tile();
+    let richest = grid.tiles()
+        .map(|tile| tile.get_amount(MatterType::SoilNutrients))
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    for (tile, mut transform, mut sprite) in tile_query.iter_mut() {
+        let (center, size) = screen_layout.garden_tile_geometry(tile.position.x, tile.position.y, grid.width(), grid.height());
+        let nutrients = grid.tile(tile.position).get_amount(MatterType::SoilNutrients);
+
+        transform.translation = center.extend(1.0);
+        sprite.custom_size = Some(size * 0.95);
+        sprite.color = match &placing {
+            Some(species) => placement_hint_color(ecosystem_state.is_suitable_tile(species, tile.position)),
+            None => tile_color(nutrients as f32 / richest as f32),
+        };
+    }
+
+    for (label, mut text, mut transform, mut text_font) in label_query.iter_mut() {
+        let (center, _size) = screen_layout.garden_tile_geometry(label.position.x, label.position.y, grid.width(), grid.height());
+        let matter = grid.tile(label.position);
+
+        transform.translation = center.extend(1.5);
+        text_font.font_size = screen_layout.text_font_size(FontSizeClass::Small);
+        **text = format!(
+            "{} creatures\nN {} / W {}",
+            population.get(&label.position).copied().unwrap_or(0),
+            matter.get_amount(MatterType::SoilNutrients),
+            matter.get_amount(MatterType::GroundWater),
+        );
+    }
+}
+
 pub fn update_resource_display(
     ecosystem_state: Res<EcosystemPopulation>,
     mut text_query: Query<&mut Text2d, With<ResourceDisplayText>>,
 ) {
     if ecosystem_state.is_changed() {
-        let mut resource_text = String::from("Ecosystem Matter:");
-        let matter = &ecosystem_state.ecosystem_matter;
+        let mut resource_text = String::from("Ecosystem Matter (all tiles):");
+        let matter = ecosystem_state.grid.total_matter();
         
         resource_text.push_str(&format!("\nSoil Nutrients: {}", matter.get_amount(MatterType::SoilNutrients)));
         resource_text.push_str(&format!("\nDead Plant Matter: {}", matter.get_amount(MatterType::DeadPlantMatter)));
         resource_text.push_str(&format!("\nDead Animal Matter: {}", matter.get_amount(MatterType::DeadAnimalMatter)));
+        resource_text.push_str(&format!("\nFruit: {}", matter.get_amount(MatterType::Fruit)));
         
         // Calculate total living matter
         let biomass = ecosystem_state.total_living_biomass();
@@ -78,6 +193,22 @@ pub fn update_resource_display(
         resource_text.push_str(&format!("\nLiving Animal Matter: {}", animal_matter));
         resource_text.push_str(&format!("\nDay: {}", ecosystem_state.current_day));
         
+        // Season, today's weather and what's coming
+        let climate = &ecosystem_state.climate;
+        resource_text.push_str(&format!(
+            "\n{} (day {}/{}), {}",
+            ecosystem_state.season().name(),
+            climate.day_of_season(ecosystem_state.current_day),
+            DAYS_PER_SEASON,
+            ecosystem_state.weather().name(),
+        ));
+        let forecast: Vec<&str> = climate
+            .forecast(ecosystem_state.current_day, FORECAST_DAYS)
+            .into_iter()
+            .map(|(_day, weather)| weather.name())
+            .collect();
+        resource_text.push_str(&format!("\nForecast: {}", forecast.join(", ")));
+        
         if let Ok(mut text) = text_query.single_mut() {
             **text = resource_text;
         }
@@ -91,7 +222,7 @@ pub fn update_species_display(
     if ecosystem_state.is_changed() {
         let mut species_text = String::from("Species Present:");
         
-        if ecosystem_state.creatures.is_empty() {
+        if ecosystem_state.total_population() == 0 {
             species_text.push_str("\nNo species yet");
         } else {
             // Just show which species are present, not counts
@@ -106,10 +237,36 @@ pub fn update_species_display(
             }
             
             // Optional: Show total without details
-            let total_creatures = ecosystem_state.living_creatures().count();
+            let total_creatures = ecosystem_state.total_population();
             species_text.push_str(&format!("\n({} total creatures)", total_creatures));
         }
         
+        // Cumulative deaths per species, broken down by cause
+        let mut mortality: Vec<_> = ecosystem_state.mortality_by_species
+            .iter()
+            .filter(|(_, stats)| stats.total() > 0)
+            .collect();
+        mortality.sort_by(|a, b| a.0.cmp(b.0));
+        
+        if !mortality.is_empty() {
+            species_text.push_str("\n\nDeaths:");
+            for (species_name, stats) in mortality {
+                let causes: Vec<String> = [
+                    DeathCause::Starvation,
+                    DeathCause::NaturalAge,
+                    DeathCause::EnvironmentalStress,
+                    DeathCause::Predation,
+                    DeathCause::Disaster,
+                    DeathCause::Disease,
+                ]
+                    .iter()
+                    .filter(|cause| stats.count(cause) > 0)
+                    .map(|cause| format!("{} {}", stats.count(cause), cause.name()))
+                    .collect();
+                species_text.push_str(&format!("\n- {}: {}", species_name, causes.join(", ")));
+            }
+        }
+        
         if let Ok(mut text) = text_query.single_mut() {
             **text = species_text;
         }
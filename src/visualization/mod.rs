@@ -2,8 +2,10 @@ pub mod cards;
 pub mod display;
 pub mod garden;
 pub mod ui;
+pub mod plugin;
 
 pub use cards::*;
 pub use display::*;
 pub use garden::*;
 pub use ui::*;
+pub use plugin::EdenUiPlugin;
@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+use crate::gameplay::lifecycle::handle_simulate_day_event;
+use crate::visualization::*;
+
+/// Layout, hand, buttons, garden display, event log and notifications
+/// Requires `EdenGameplayPlugin` and a windowed app (e.g. `DefaultPlugins`)
+pub struct EdenUiPlugin;
+
+impl Plugin for EdenUiPlugin {
+    fn build(&self, app: &mut App) {
+        // Add resources
+        app.init_resource::<ScreenLayout>();
+        app.init_resource::<SelectedCard>();
+        app.init_resource::<EventLogView>();
+        app.init_resource::<ActiveNotification>();
+        app.init_resource::<PlacementTargeting>();
+
+        // Add startup systems
+        app.add_systems(Startup, (
+            init_ui_elements,
+            init_screen_layout,
+        ).chain());
+
+        // Add update systems
+        app.add_systems(Update, (
+            handle_window_resize,
+            handle_card_clicks,
+            handle_button_clicks,
+            handle_placement_clicks,
+            update_button_visuals,
+            update_button_layout,
+            update_resource_display,
+            update_species_display,
+            update_garden_tiles,
+            update_hand_ui,
+            update_hand_layout,
+            update_card_visuals,
+            clear_selection_after_actions,
+            update_event_log_layout,
+            update_event_log_text,
+            handle_event_log_filter_clicks,
+            handle_event_log_scroll,
+        ));
+
+        app.add_systems(Update, (
+            show_simulation_failures,
+            announce_ecological_events,
+            announce_placement_targeting,
+            update_notification_banner,
+        ).chain().after(handle_simulate_day_event));
+    }
+}
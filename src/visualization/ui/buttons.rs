@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use crate::gameplay::cards::{PlayCardEvent, DiscardCardEvent};
 use crate::gameplay::lifecycle::SimulateDayEvent;
 use crate::visualization::ScreenLayout;
+use super::PlacementTargeting;
 use crate::visualization::display::responsive_size_utils::{FontSizeClass, ResponsiveExt};
 
 /// Component marker for action buttons
@@ -150,7 +151,7 @@ pub fn handle_button_clicks(
     windows: Query<&Window>,
     camera_query: Query<(&Camera, &GlobalTransform)>,
     selected_card: Res<SelectedCard>,
-    mut play_events: EventWriter<PlayCardEvent>,
+    mut targeting: ResMut<PlacementTargeting>,
     mut discard_events: EventWriter<DiscardCardEvent>,
     mut simulate_day_events: EventWriter<SimulateDayEvent>,
 ) {
@@ -168,7 +169,7 @@ pub fn handle_button_clicks(
                 &button_query,
                 &screen_layout,
                 &selected_card,
-                &mut play_events,
+                &mut targeting,
                 &mut discard_events,
                 &mut simulate_day_events,
             );
@@ -229,7 +230,7 @@ fn handle_button_interaction(
     button_query: &Query<(&ActionButton, &Transform), With<ButtonSprite>>,
     screen_layout: &ScreenLayout,
     selected_card: &SelectedCard,
-    play_events: &mut EventWriter<PlayCardEvent>,
+    targeting: &mut PlacementTargeting,
     discard_events: &mut EventWriter<DiscardCardEvent>,
     simulate_day_events: &mut EventWriter<SimulateDayEvent>,
 ) {
@@ -251,8 +252,9 @@ fn handle_button_interaction(
             
             match button.action {
                 ButtonAction::Play => {
+                    // The card is played once the player picks a tile for it
                     if let Some(index) = selected_card.get_selected() {
-                        play_events.write(PlayCardEvent { hand_index: index });
+                        targeting.start(index);
                     }
                 }
                 ButtonAction::Discard => {
@@ -0,0 +1,377 @@
+use bevy::prelude::*;
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::sprite::Anchor;
+use crate::gameplay::{EcosystemLog, LogEventKind};
+use crate::visualization::ScreenLayout;
+use crate::visualization::display::responsive_size_utils::{FontSizeClass, PaddingClass, ResponsiveExt};
+
+/// Component marker for the event log panel background
+#[derive(Component)]
+pub struct EventLogPanel;
+
+/// Component marker for the event log body text
+#[derive(Component)]
+pub struct EventLogText;
+
+/// Filter buttons shown at the top of the event log panel
+#[derive(Component)]
+pub struct EventLogFilterButton {
+    pub filter: LogFilter,
+}
+
+/// Component marker for filter button labels
+#[derive(Component)]
+pub struct EventLogFilterLabel {
+    pub filter: LogFilter,
+}
+
+/// Which log filter a button controls
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LogFilter {
+    Species,
+    Kind,
+}
+
+/// Resource tracking the player's view into the event log
+#[derive(Resource, Default)]
+pub struct EventLogView {
+    pub scroll_offset: usize,
+    pub species_filter: Option<String>,
+    pub kind_filter: Option<LogEventKind>,
+    scroll_remainder: f32,
+}
+
+impl EventLogView {
+    /// Advance the species filter: All -> each logged species -> All
+    pub fn cycle_species(&mut self, species_names: &[String]) {
+        self.species_filter = match &self.species_filter {
+            None => species_names.first().cloned(),
+            Some(current) => species_names
+                .iter()
+                .position(|name| name == current)
+                .and_then(|index| species_names.get(index + 1))
+                .cloned(),
+        };
+        self.scroll_offset = 0;
+    }
+
+    /// Advance the event kind filter: All -> each kind -> All
+    pub fn cycle_kind(&mut self) {
+        let kinds = LogEventKind::all();
+        self.kind_filter = match self.kind_filter {
+            None => kinds.first().copied(),
+            Some(current) => kinds
+                .iter()
+                .position(|kind| *kind == current)
+                .and_then(|index| kinds.get(index + 1))
+                .copied(),
+        };
+        self.scroll_offset = 0;
+    }
+
+    /// Flatten the filtered log into display lines, newest day first
+    pub fn lines(&self, event_log: &EcosystemLog) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        for (day, entries) in event_log.grouped_by_day(self.species_filter.as_deref(), self.kind_filter) {
+            lines.push(format!("Day {}:", day));
+            for entry in entries {
+                lines.push(format!("  {}", entry.message));
+            }
+        }
+
+        lines
+    }
+
+    /// Scroll by a number of lines (positive shows older entries)
+    pub fn scroll_lines(&mut self, lines: f32, total_lines: usize, visible_lines: usize) {
+        self.scroll_remainder += lines;
+        let whole_lines = self.scroll_remainder.trunc();
+        self.scroll_remainder -= whole_lines;
+
+        let max_offset = total_lines.saturating_sub(visible_lines) as isize;
+        let new_offset = (self.scroll_offset as isize + whole_lines as isize).clamp(0, max_offset);
+        self.scroll_offset = new_offset as usize;
+    }
+
+    fn filter_label(&self, filter: LogFilter) -> String {
+        match filter {
+            LogFilter::Species => format!("Species: {}", self.species_filter.as_deref().unwrap_or("All")),
+            LogFilter::Kind => format!("Kind: {}", self.kind_filter.map(|kind| kind.name()).unwrap_or("All")),
+        }
+    }
+}
+
+/// Spawn the event log panel in the lower part of the garden
+pub fn init_event_log_panel(commands: &mut Commands, screen_layout: &ScreenLayout) {
+    let panel_size = screen_layout.event_log_panel_size();
+    let panel_center = screen_layout.event_log_panel_center();
+
+    commands.spawn((
+        Sprite {
+            color: Color::srgba(0.0, 0.0, 0.0, 0.35),
+            custom_size: Some(panel_size),
+            ..default()
+        },
+        Transform::from_translation(panel_center.extend(2.0)),
+        EventLogPanel,
+    ));
+
+    for filter in [LogFilter::Species, LogFilter::Kind] {
+        let (center, size) = filter_button_geometry(screen_layout, filter);
+
+        let button_entity = commands.spawn((
+            Sprite {
+                color: Color::srgb(0.25, 0.4, 0.25),
+                custom_size: Some(size),
+                ..default()
+            },
+            Transform::from_translation(center.extend(3.0)),
+            EventLogFilterButton { filter },
+        )).id();
+
+        let label_entity = commands.spawn((
+            Text2d::new(EventLogView::default().filter_label(filter)),
+            TextFont {
+                font_size: screen_layout.text_font_size(FontSizeClass::Small),
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Transform::from_translation(Vec3::new(0.0, 0.0, 1.0)),
+            EventLogFilterLabel { filter },
+        )).id();
+
+        commands.entity(button_entity).add_child(label_entity);
+    }
+
+    commands.spawn((
+        Text2d::new("No events yet"),
+        TextFont {
+            font_size: screen_layout.text_font_size(FontSizeClass::Small),
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        TextLayout::new_with_justify(JustifyText::Left),
+        Anchor::TopLeft,
+        Transform::from_translation(body_text_position(screen_layout).extend(3.0)),
+        EventLogText,
+    ));
+}
+
+/// Refresh the visible log lines and filter labels
+pub fn update_event_log_text(
+    event_log: Res<EcosystemLog>,
+    view: Res<EventLogView>,
+    screen_layout: Res<ScreenLayout>,
+    mut body_query: Query<&mut Text2d, (With<EventLogText>, Without<EventLogFilterLabel>)>,
+    mut label_query: Query<(&mut Text2d, &EventLogFilterLabel), Without<EventLogText>>,
+) {
+    if !(event_log.is_changed() || view.is_changed() || screen_layout.is_changed()) {
+        return;
+    }
+
+    let lines = view.lines(&event_log);
+    let visible_lines = visible_line_count(&screen_layout);
+    let body = if lines.is_empty() {
+        "No events yet".to_string()
+    } else {
+        lines
+            .iter()
+            .skip(view.scroll_offset.min(lines.len().saturating_sub(visible_lines)))
+            .take(visible_lines)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    if let Ok(mut text) = body_query.single_mut() {
+        **text = body;
+    }
+
+    for (mut text, label) in label_query.iter_mut() {
+        **text = view.filter_label(label.filter);
+    }
+}
+
+/// Cycle log filters when their buttons are clicked
+pub fn handle_event_log_filter_clicks(
+    button_query: Query<(&EventLogFilterButton, &Transform, &Sprite)>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    event_log: Res<EcosystemLog>,
+    mut view: ResMut<EventLogView>,
+) {
+    let Ok(window) = windows.single() else { return };
+    let Ok((camera, camera_transform)) = camera_query.single() else { return };
+
+    let Some(screen_pos) = get_interaction_position(&mouse_input, &touches, window) else { return };
+    let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, screen_pos) else { return };
+
+    for (button, transform, sprite) in button_query.iter() {
+        let size = sprite.custom_size.unwrap_or_default();
+        if is_point_in_rect(world_pos, transform.translation.truncate(), size) {
+            match button.filter {
+                LogFilter::Species => view.cycle_species(&event_log.species_names()),
+                LogFilter::Kind => view.cycle_kind(),
+            }
+            break;
+        }
+    }
+}
+
+/// Scroll the log with the mouse wheel or a touch drag over the panel
+pub fn handle_event_log_scroll(
+    mut wheel_events: EventReader<MouseWheel>,
+    touches: Res<Touches>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    screen_layout: Res<ScreenLayout>,
+    event_log: Res<EcosystemLog>,
+    mut view: ResMut<EventLogView>,
+) {
+    let Ok(window) = windows.single() else { return };
+    let Ok((camera, camera_transform)) = camera_query.single() else { return };
+
+    let panel_center = screen_layout.event_log_panel_center();
+    let panel_size = screen_layout.event_log_panel_size();
+    let is_over_panel = |screen_pos: Vec2| {
+        camera
+            .viewport_to_world_2d(camera_transform, screen_pos)
+            .is_ok_and(|world_pos| is_point_in_rect(world_pos, panel_center, panel_size))
+    };
+
+    let line_height = line_height(&screen_layout);
+    let mut scroll = 0.0;
+
+    // Mouse wheel scrolls while hovering the panel (wheel up shows newer entries)
+    let cursor_over_panel = window.cursor_position().is_some_and(&is_over_panel);
+    for event in wheel_events.read() {
+        if cursor_over_panel {
+            scroll -= match event.unit {
+                MouseScrollUnit::Line => event.y,
+                MouseScrollUnit::Pixel => event.y / line_height,
+            };
+        }
+    }
+
+    // Dragging a finger up reveals older entries
+    for touch in touches.iter() {
+        if is_over_panel(touch.start_position()) {
+            scroll -= touch.delta().y / line_height;
+        }
+    }
+
+    if scroll != 0.0 {
+        let total_lines = view.lines(&event_log).len();
+        view.scroll_lines(scroll, total_lines, visible_line_count(&screen_layout));
+    }
+}
+
+type PanelLayoutQuery<'w, 's> = Query<'w, 's, (&'static mut Transform, &'static mut Sprite), (With<EventLogPanel>, Without<EventLogFilterButton>)>;
+type BodyLayoutQuery<'w, 's> = Query<'w, 's, (&'static mut Transform, &'static mut TextFont), (With<EventLogText>, Without<EventLogPanel>, Without<EventLogFilterButton>)>;
+
+/// Update panel positions and sizes when the screen layout changes
+pub fn update_event_log_layout(
+    screen_layout: Res<ScreenLayout>,
+    mut panel_query: PanelLayoutQuery,
+    mut button_query: Query<(&mut Transform, &mut Sprite, &EventLogFilterButton), Without<EventLogPanel>>,
+    mut text_query: BodyLayoutQuery,
+    mut label_query: Query<&mut TextFont, (With<EventLogFilterLabel>, Without<EventLogText>)>,
+) {
+    if !screen_layout.is_changed() {
+        return;
+    }
+
+    for (mut transform, mut sprite) in panel_query.iter_mut() {
+        transform.translation = screen_layout.event_log_panel_center().extend(2.0);
+        sprite.custom_size = Some(screen_layout.event_log_panel_size());
+    }
+
+    for (mut transform, mut sprite, button) in button_query.iter_mut() {
+        let (center, size) = filter_button_geometry(&screen_layout, button.filter);
+        transform.translation = center.extend(3.0);
+        sprite.custom_size = Some(size);
+    }
+
+    let font_size = screen_layout.text_font_size(FontSizeClass::Small);
+    for (mut transform, mut text_font) in text_query.iter_mut() {
+        transform.translation = body_text_position(&screen_layout).extend(3.0);
+        text_font.font_size = font_size;
+    }
+
+    for mut text_font in label_query.iter_mut() {
+        text_font.font_size = font_size;
+    }
+}
+
+/// Height of one line of log text
+fn line_height(screen_layout: &ScreenLayout) -> f32 {
+    screen_layout.text_font_size(FontSizeClass::Small) * 1.2
+}
+
+/// Height of the filter button row at the top of the panel
+fn filter_row_height(screen_layout: &ScreenLayout) -> f32 {
+    line_height(screen_layout) * 1.5
+}
+
+/// Number of log lines that fit below the filter row
+fn visible_line_count(screen_layout: &ScreenLayout) -> usize {
+    let padding = screen_layout.pad(PaddingClass::Small);
+    let body_height = screen_layout.event_log_panel_size().y - filter_row_height(screen_layout) - padding * 3.0;
+    (body_height / line_height(screen_layout)).floor().max(1.0) as usize
+}
+
+/// Center and size of a filter button
+fn filter_button_geometry(screen_layout: &ScreenLayout, filter: LogFilter) -> (Vec2, Vec2) {
+    let panel_center = screen_layout.event_log_panel_center();
+    let panel_size = screen_layout.event_log_panel_size();
+    let padding = screen_layout.pad(PaddingClass::Small);
+
+    let size = Vec2::new((panel_size.x - padding * 3.0) / 2.0, filter_row_height(screen_layout));
+    let y = panel_center.y + panel_size.y / 2.0 - padding - size.y / 2.0;
+    let x = match filter {
+        LogFilter::Species => panel_center.x - (padding + size.x) / 2.0,
+        LogFilter::Kind => panel_center.x + (padding + size.x) / 2.0,
+    };
+
+    (Vec2::new(x, y), size)
+}
+
+/// Top-left corner of the log body text
+fn body_text_position(screen_layout: &ScreenLayout) -> Vec2 {
+    let panel_center = screen_layout.event_log_panel_center();
+    let panel_size = screen_layout.event_log_panel_size();
+    let padding = screen_layout.pad(PaddingClass::Small);
+
+    Vec2::new(
+        panel_center.x - panel_size.x / 2.0 + padding,
+        panel_center.y + panel_size.y / 2.0 - filter_row_height(screen_layout) - padding * 2.0,
+    )
+}
+
+/// Check if a point is inside an axis-aligned rectangle
+fn is_point_in_rect(point: Vec2, center: Vec2, size: Vec2) -> bool {
+    let half_size = size / 2.0;
+
+    point.x >= center.x - half_size.x &&
+    point.x <= center.x + half_size.x &&
+    point.y >= center.y - half_size.y &&
+    point.y <= center.y + half_size.y
+}
+
+/// Gets the position of user interaction (mouse click or touch)
+fn get_interaction_position(
+    mouse_input: &ButtonInput<MouseButton>,
+    touches: &Touches,
+    window: &Window,
+) -> Option<Vec2> {
+    // Check for mouse click
+    if mouse_input.just_pressed(MouseButton::Left) {
+        return window.cursor_position();
+    }
+
+    // Check for touch input (handle first touch only)
+    touches.iter_just_pressed().next().map(|touch| touch.position())
+}
@@ -1,3 +1,9 @@
 pub mod buttons;
+pub mod event_log;
+pub mod notifications;
+pub mod placement;
 
 pub use buttons::*;
+pub use event_log::*;
+pub use notifications::*;
+pub use placement::*;
@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+use crate::gameplay::lifecycle::{EcologicalEventOccurred, SimulationFailedEvent};
+use crate::visualization::ScreenLayout;
+use crate::visualization::display::responsive_size_utils::{FontSizeClass, PaddingClass, ResponsiveExt};
+
+/// How long a notification stays on screen, in seconds
+const NOTIFICATION_DURATION_SECS: f32 = 5.0;
+
+/// Component marker for the notification banner text
+#[derive(Component)]
+pub struct NotificationText;
+
+/// Resource tracking the notification currently shown to the player
+#[derive(Resource, Default)]
+pub struct ActiveNotification {
+    pub message: Option<String>,
+    timer: Timer,
+}
+
+impl ActiveNotification {
+    /// Show a message, replacing any notification already on screen
+    pub fn show(&mut self, message: String) {
+        self.message = Some(message);
+        self.timer = Timer::from_seconds(NOTIFICATION_DURATION_SECS, TimerMode::Once);
+    }
+}
+
+/// Spawn the (initially hidden) notification banner at the top of the garden
+pub fn init_notification_banner(commands: &mut Commands, screen_layout: &ScreenLayout) {
+    commands.spawn((
+        Text2d::new(""),
+        TextFont {
+            font_size: screen_layout.text_font_size(FontSizeClass::Medium),
+            ..default()
+        },
+        TextColor(Color::srgb(1.0, 0.6, 0.4)),
+        Transform::from_translation(notification_position(screen_layout)),
+        Visibility::Hidden,
+        NotificationText,
+    ));
+}
+
+fn notification_position(screen_layout: &ScreenLayout) -> Vec3 {
+    Vec3::new(
+        screen_layout.garden_center.x,
+        screen_layout.garden_center.y + screen_layout.garden_area.y / 2.0 - screen_layout.pad(PaddingClass::Large),
+        20.0,
+    )
+}
+
+/// System to turn failed days into a player notification
+pub fn show_simulation_failures(
+    mut failure_events: EventReader<SimulationFailedEvent>,
+    mut notification: ResMut<ActiveNotification>,
+) {
+    for event in failure_events.read() {
+        notification.show(format!(
+            "Day {} couldn't be simulated and was undone: {}",
+            event.day + 1,
+            event.message
+        ));
+    }
+}
+
+/// System to announce random ecological events to the player
+pub fn announce_ecological_events(
+    mut ecological_events: EventReader<EcologicalEventOccurred>,
+    mut notification: ResMut<ActiveNotification>,
+) {
+    for event in ecological_events.read() {
+        notification.show(format!("{}! {}", event.kind.name(), event.description));
+    }
+}
+
+/// System to display the active notification and hide it once it expires
+pub fn update_notification_banner(
+    time: Res<Time>,
+    screen_layout: Res<ScreenLayout>,
+    mut notification: ResMut<ActiveNotification>,
+    mut banner_query: Query<(&mut Text2d, &mut Visibility, &mut Transform), With<NotificationText>>,
+) {
+    notification.timer.tick(time.delta());
+    if notification.timer.just_finished() {
+        notification.message = None;
+    }
+
+    for (mut text, mut visibility, mut transform) in banner_query.iter_mut() {
+        match &notification.message {
+            Some(message) => {
+                if text.0 != *message {
+                    text.0 = message.clone();
+                }
+                *visibility = Visibility::Visible;
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+
+        if screen_layout.is_changed() {
+            transform.translation = notification_position(&screen_layout);
+        }
+    }
+}
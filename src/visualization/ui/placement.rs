@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use crate::gameplay::cards::PlayCardEvent;
+use crate::gameplay::lifecycle::{EcosystemPopulation, TilePosition};
+use crate::gameplay::species::{get_species, Species};
+use crate::visualization::ScreenLayout;
+use super::SelectedCard;
+
+/// Resource tracking a played card waiting for the player to pick a garden tile
+#[derive(Resource, Default)]
+pub struct PlacementTargeting {
+    pub hand_index: Option<usize>,
+}
+
+impl PlacementTargeting {
+    pub fn start(&mut self, hand_index: usize) {
+        self.hand_index = Some(hand_index);
+    }
+
+    pub fn cancel(&mut self) {
+        self.hand_index = None;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.hand_index.is_some()
+    }
+}
+
+/// Species of the card being placed, if targeting is active
+pub fn targeted_species(targeting: &PlacementTargeting, game_state: &crate::gameplay::GameState) -> Option<Species> {
+    let card = game_state.hand.get_card(targeting.hand_index?)?;
+    get_species(card.name()).cloned()
+}
+
+/// Mouse and touch input, resolved to the world position of this frame's tap
+#[derive(SystemParam)]
+pub struct TapInput<'w, 's> {
+    mouse_input: Res<'w, ButtonInput<MouseButton>>,
+    touches: Res<'w, Touches>,
+    windows: Query<'w, 's, &'static Window>,
+    camera_query: Query<'w, 's, (&'static Camera, &'static GlobalTransform)>,
+}
+
+impl TapInput<'_, '_> {
+    /// World position of a click or the first new touch this frame
+    pub fn world_position(&self) -> Option<Vec2> {
+        let window = self.windows.single().ok()?;
+        let (camera, camera_transform) = self.camera_query.single().ok()?;
+
+        let screen_pos = if self.mouse_input.just_pressed(MouseButton::Left) {
+            window.cursor_position()
+        } else {
+            self.touches.iter_just_pressed().next().map(|touch| touch.position())
+        }?;
+        camera.viewport_to_world_2d(camera_transform, screen_pos).ok()
+    }
+}
+
+/// Handles tapping a garden tile while targeting, playing the card onto that tile
+pub fn handle_placement_clicks(
+    mut targeting: ResMut<PlacementTargeting>,
+    selected_card: Res<SelectedCard>,
+    ecosystem: Res<EcosystemPopulation>,
+    screen_layout: Res<ScreenLayout>,
+    tap_input: TapInput,
+    mut play_events: EventWriter<PlayCardEvent>,
+) {
+    let Some(hand_index) = targeting.hand_index else { return };
+
+    // Picking a different card (or deselecting) abandons the placement
+    if selected_card.get_selected() != Some(hand_index) {
+        targeting.cancel();
+        return;
+    }
+
+    let Some(world_pos) = tap_input.world_position() else { return };
+
+    let grid = &ecosystem.grid;
+    if let Some((x, y)) = screen_layout.garden_tile_at(world_pos, grid.width(), grid.height()) {
+        play_events.write(PlayCardEvent { hand_index, location: TilePosition::new(x, y) });
+        targeting.cancel();
+    }
+}
+
+/// Tell the player how to place the card once targeting starts
+pub fn announce_placement_targeting(
+    targeting: Res<PlacementTargeting>,
+    game_state: Res<crate::gameplay::GameState>,
+    mut notification: ResMut<super::ActiveNotification>,
+) {
+    if !targeting.is_changed() {
+        return;
+    }
+
+    if let Some(species) = targeted_species(&targeting, &game_state) {
+        notification.show(format!("Tap a tile to place {} (green tiles suit it)", species.name));
+    }
+}
@@ -0,0 +1,65 @@
+//! Full turns driven through the headless app's events, with no window or renderer
+
+use bevy::prelude::*;
+use eden2::create_headless_app;
+use eden2::gameplay::{DiscardCardEvent, EcosystemPopulation, GameState, PlayCardEvent};
+use eden2::gameplay::lifecycle::{SimulateDayEvent, TilePosition};
+
+/// Cards still to come, in hand or deck; every play or discard uses one up
+fn cards_left(app: &App) -> usize {
+    let game_state = app.world().resource::<GameState>();
+    game_state.hand.len() + game_state.deck.len()
+}
+
+fn current_day(app: &App) -> u32 {
+    app.world().resource::<EcosystemPopulation>().current_day
+}
+
+#[test]
+fn playing_a_card_introduces_its_species_without_ending_the_day() {
+    let mut app = create_headless_app();
+    let cards = cards_left(&app);
+    let species = app.world().resource::<GameState>().hand.get_card(0).unwrap().name().to_string();
+    let tile = TilePosition { x: 1, y: 2 };
+
+    app.world_mut().send_event(PlayCardEvent { hand_index: 0, location: tile });
+    app.update();
+
+    assert_eq!(cards_left(&app), cards - 1);
+    let ecosystem = app.world().resource::<EcosystemPopulation>();
+    assert_eq!(ecosystem.population_count(&species), 1);
+    assert!(ecosystem.creatures_by_species(&species).all(|creature| creature.tile == tile));
+    assert_eq!(ecosystem.current_day, 0);
+}
+
+#[test]
+fn discarding_a_card_ends_the_day() {
+    let mut app = create_headless_app();
+    let cards = cards_left(&app);
+    let game_state = app.world().resource::<GameState>();
+    // A replacement is drawn while the deck lasts
+    let hand_size = game_state.hand.len() - usize::from(game_state.deck.is_empty());
+
+    app.world_mut().send_event(DiscardCardEvent { hand_index: 0 });
+    app.update();
+
+    assert_eq!(cards_left(&app), cards - 1);
+    assert_eq!(app.world().resource::<GameState>().hand.len(), hand_size);
+    assert_eq!(current_day(&app), 1);
+}
+
+#[test]
+fn simulated_days_advance_the_garden_and_keep_the_hand() {
+    let mut app = create_headless_app();
+    app.world_mut().send_event(PlayCardEvent { hand_index: 0, location: TilePosition { x: 0, y: 0 } });
+    app.update();
+    let cards = cards_left(&app);
+
+    for _ in 0..3 {
+        app.world_mut().send_event(SimulateDayEvent);
+        app.update();
+    }
+
+    assert_eq!(current_day(&app), 3);
+    assert_eq!(cards_left(&app), cards);
+}